@@ -5,7 +5,6 @@ use std::env;
 use std::fs::File;
 use std::io::{Read, Write};
 use std::path::PathBuf;
-use std::u8;
 
 use json::JsonValue;
 
@@ -141,6 +140,251 @@ fn get_ec(symbol: &str, ec: &mut HashMap<String, EC>) -> ElectronicConfiguration
     }
 }
 
+/// A comma separated table bundled in the `data` directory, keyed by the
+/// element symbol in its first column. An element may span several rows.
+struct Table {
+    columns: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    fn load(name: &str) -> Table {
+        let mut path = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+        path.push("data");
+        path.push(name);
+        let mut contents = String::new();
+        File::open(path)
+            .unwrap()
+            .read_to_string(&mut contents)
+            .unwrap();
+        let mut lines = contents.lines().filter(|l| !l.trim().is_empty());
+        let columns = lines
+            .next()
+            .unwrap()
+            .split(',')
+            .map(|c| c.trim().to_owned())
+            .collect();
+        let rows = lines
+            .map(|line| line.split(',').map(|c| c.trim().to_owned()).collect())
+            .collect();
+        Table { columns, rows }
+    }
+
+    fn column(&self, column: &str) -> usize {
+        self.columns
+            .iter()
+            .position(|c| c == column)
+            .unwrap_or_else(|| panic!("Unknown column: {}", column))
+    }
+
+    /// All the rows of `symbol`, in file order.
+    fn rows_of<'a>(&'a self, symbol: &'a str) -> impl Iterator<Item = &'a Vec<String>> + 'a {
+        self.rows.iter().filter(move |row| row[0] == symbol)
+    }
+
+    /// Returns the cell of `column` for `symbol`, or an empty string if missing.
+    fn get<'a>(&'a self, symbol: &'a str, column: &str) -> &'a str {
+        let i = self.column(column);
+        match self.rows_of(symbol).next() {
+            Some(row) => row.get(i).map(|c| &c[..]).unwrap_or(""),
+            None => "",
+        }
+    }
+}
+
+/// Writes a `[f32; 118]` constant from a column of `table`, using `0.` for missing values.
+fn write_f32_table(out_file: &mut File, name: &str, data: &[Record], table: &Table, column: &str) {
+    out_file
+        .write_all(format!("const {}: [f32; 118] = [", name).as_bytes())
+        .unwrap();
+    let mut first = true;
+    for record in data.iter() {
+        if first {
+            first = false;
+        } else {
+            out_file.write_all(b", ").unwrap();
+        }
+        let value = table.get(&record.symbol, column);
+        if value.is_empty() {
+            out_file.write_all(b"0.").unwrap();
+            continue;
+        }
+        out_file.write_all(value.as_bytes()).unwrap();
+        if value.find('.').is_none() {
+            out_file.write_all(b".").unwrap();
+        }
+    }
+    out_file.write_all(b"];\n").unwrap();
+}
+
+/// Writes the items of all the elements, in the order of `data`, as a static
+/// `data_name` array of `ty`, and a `[(u16, u8); 118]` constant `ranges_name`
+/// with the start and the number of the items of each element.
+fn write_ranged_tables<F>(
+    out_file: &mut File,
+    data_name: &str,
+    ranges_name: &str,
+    ty: &str,
+    data: &[Record],
+    mut items: F,
+) where
+    F: FnMut(usize, &Record) -> Vec<String>,
+{
+    let items: Vec<Vec<String>> = data
+        .iter()
+        .enumerate()
+        .map(|(i, record)| items(i, record))
+        .collect();
+    let all = items.concat();
+    out_file
+        .write_all(format!("static {}: [{}; {}] = [", data_name, ty, all.len()).as_bytes())
+        .unwrap();
+    out_file.write_all(all.join(", ").as_bytes()).unwrap();
+    out_file.write_all(b"];\n").unwrap();
+    out_file
+        .write_all(format!("const {}: [(u16, u8); 118] = [", ranges_name).as_bytes())
+        .unwrap();
+    let mut start = 0;
+    let mut first = true;
+    for element_items in items.iter() {
+        if first {
+            first = false;
+        } else {
+            out_file.write_all(b", ").unwrap();
+        }
+        assert!(
+            start <= u16::MAX as usize && element_items.len() <= u8::MAX as usize,
+            "{} is too large for its ranges",
+            data_name
+        );
+        out_file
+            .write_all(format!("({}, {})", start, element_items.len()).as_bytes())
+            .unwrap();
+        start += element_items.len();
+    }
+    out_file.write_all(b"];\n").unwrap();
+}
+
+/// Writes a `[Option<[u8; 3]>; 118]` constant from a column of `table` holding
+/// hexadecimal colours such as "FF0D0D".
+fn write_color_table(
+    out_file: &mut File,
+    name: &str,
+    data: &[Record],
+    table: &Table,
+    column: &str,
+) {
+    out_file
+        .write_all(format!("const {}: [Option<[u8; 3]>; 118] = [", name).as_bytes())
+        .unwrap();
+    let mut first = true;
+    for record in data.iter() {
+        if first {
+            first = false;
+        } else {
+            out_file.write_all(b", ").unwrap();
+        }
+        let hex = table.get(&record.symbol, column).as_bytes();
+        if hex.is_empty() {
+            out_file.write_all(b"None").unwrap();
+            continue;
+        }
+        assert_eq!(hex.len(), 6, "invalid colour for {}", record.symbol);
+        out_file
+            .write_all(
+                format!(
+                    "Some([{}, {}, {}])",
+                    parse_dhex(&hex[0..]),
+                    parse_dhex(&hex[2..]),
+                    parse_dhex(&hex[4..])
+                )
+                .as_bytes(),
+            )
+            .unwrap();
+    }
+    out_file.write_all(b"];\n").unwrap();
+}
+
+/// Writes a `[Option<&str>; 118]` (or `[u32; 118]` if `numeric`, using `0`
+/// for missing values) constant from a column of `table`, and the known
+/// values sorted for reverse lookups as `{name}_SORTED`.
+fn write_identifier_tables(
+    out_file: &mut File,
+    name: &str,
+    data: &[Record],
+    table: &Table,
+    column: &str,
+    numeric: bool,
+) {
+    let ty = if numeric { "u32" } else { "&str" };
+    let literal = |value: &str| {
+        if numeric {
+            value.to_owned()
+        } else {
+            format!("\"{}\"", value)
+        }
+    };
+    let (array_ty, missing) = if numeric {
+        ("u32", "0")
+    } else {
+        ("Option<&str>", "None")
+    };
+    out_file
+        .write_all(format!("const {}: [{}; 118] = [", name, array_ty).as_bytes())
+        .unwrap();
+    let mut sorted = Vec::with_capacity(118);
+    let mut first = true;
+    for (i, record) in data.iter().enumerate() {
+        if first {
+            first = false;
+        } else {
+            out_file.write_all(b", ").unwrap();
+        }
+        let value = table.get(&record.symbol, column);
+        if value.is_empty() {
+            out_file.write_all(missing.as_bytes()).unwrap();
+            continue;
+        }
+        sorted.push((value, i));
+        if numeric {
+            out_file.write_all(literal(value).as_bytes()).unwrap();
+        } else {
+            out_file
+                .write_all(format!("Some({})", literal(value)).as_bytes())
+                .unwrap();
+        }
+    }
+    out_file.write_all(b"];\n").unwrap();
+    if numeric {
+        sorted.sort_unstable_by_key(|(value, _)| value.parse::<u32>().unwrap());
+    } else {
+        sorted.sort_unstable();
+    }
+    out_file
+        .write_all(
+            format!(
+                "const {}_SORTED: [({}, u8); {}] = [",
+                name,
+                ty,
+                sorted.len()
+            )
+            .as_bytes(),
+        )
+        .unwrap();
+    first = true;
+    for (value, i) in sorted {
+        if first {
+            first = false;
+        } else {
+            out_file.write_all(b", ").unwrap();
+        }
+        out_file
+            .write_all(format!("({}, {})", literal(value), i).as_bytes())
+            .unwrap();
+    }
+    out_file.write_all(b"];\n").unwrap();
+}
+
 fn main() {
     let mut ec = HashMap::new();
     let mut oxn = 0;
@@ -748,4 +992,757 @@ fn main() {
             .unwrap();
     }
     out_file.write_all(b"];\n").unwrap();
+    let electronegativities = Table::load("electronegativities.csv");
+    write_f32_table(
+        &mut out_file,
+        "ALLEN_ELECTRONEGATIVITIES",
+        &data,
+        &electronegativities,
+        "Allen",
+    );
+    write_f32_table(
+        &mut out_file,
+        "ALLRED_ROCHOW_ELECTRONEGATIVITIES",
+        &data,
+        &electronegativities,
+        "AllredRochow",
+    );
+    write_f32_table(
+        &mut out_file,
+        "SANDERSON_ELECTRONEGATIVITIES",
+        &data,
+        &electronegativities,
+        "Sanderson",
+    );
+    let radii = Table::load("radii.csv");
+    write_f32_table(
+        &mut out_file,
+        "COVALENT_RADII_SINGLE",
+        &data,
+        &radii,
+        "CovalentSingle",
+    );
+    write_f32_table(
+        &mut out_file,
+        "COVALENT_RADII_DOUBLE",
+        &data,
+        &radii,
+        "CovalentDouble",
+    );
+    write_f32_table(
+        &mut out_file,
+        "COVALENT_RADII_TRIPLE",
+        &data,
+        &radii,
+        "CovalentTriple",
+    );
+    write_f32_table(&mut out_file, "METALLIC_RADII", &data, &radii, "Metallic");
+    let ionic_radii = Table::load("ionic_radii.csv");
+    let charge = ionic_radii.column("Charge");
+    let coordination = ionic_radii.column("Coordination");
+    let radius = ionic_radii.column("Radius");
+    write_ranged_tables(
+        &mut out_file,
+        "IONIC_RADII_DATA",
+        "IONIC_RADII",
+        "IonicRadius",
+        &data,
+        |_, record| {
+            ionic_radii
+                .rows_of(&record.symbol)
+                .map(|row| {
+                    let mut r = row[radius].clone();
+                    if r.find('.').is_none() {
+                        r.push('.');
+                    }
+                    format!(
+                        "IonicRadius {} charge: {}, coordination: {}, radius: {} {}",
+                        "{", row[charge], row[coordination], r, "}"
+                    )
+                })
+                .collect()
+        },
+    );
+    let ionization_energies = Table::load("ionization_energies.csv");
+    write_ranged_tables(
+        &mut out_file,
+        "IONIZATION_ENERGIES_DATA",
+        "IONIZATION_ENERGIES_SERIES",
+        "f32",
+        &data,
+        |_, record| {
+            ionization_energies
+                .get(&record.symbol, "IonizationEnergies")
+                .split_whitespace()
+                .map(|e| {
+                    if e.find('.').is_none() {
+                        format!("{}.", e)
+                    } else {
+                        e.to_owned()
+                    }
+                })
+                .collect()
+        },
+    );
+    let historical_names = Table::load("historical_names.csv");
+    let name = historical_names.column("Name");
+    let historical_symbol = historical_names.column("HistoricalSymbol");
+    let kind = historical_names.column("Kind");
+    let from = historical_names.column("From");
+    let until = historical_names.column("Until");
+    let optional = |v: &str, wrap: &dyn Fn(&str) -> String| {
+        if v.is_empty() {
+            "None".to_owned()
+        } else {
+            format!("Some({})", wrap(v))
+        }
+    };
+    let mut historical_lowercase_names = Vec::new();
+    let mut historical_symbols = Vec::new();
+    write_ranged_tables(
+        &mut out_file,
+        "NAME_HISTORY_DATA",
+        "NAME_HISTORY",
+        "NameRecord",
+        &data,
+        |i, record| {
+            let mut rows: Vec<&Vec<String>> = historical_names.rows_of(&record.symbol).collect();
+            rows.sort_by_key(|row| row[from].parse::<u16>().unwrap_or(0));
+            rows.iter()
+                .map(|row| {
+                    // Only the symbol of these records is historical
+                    if row[kind] != "FormerSymbol" {
+                        historical_lowercase_names.push((row[name].to_lowercase(), i));
+                    }
+                    // The symbol origins and variants still use the current
+                    // symbol
+                    if !row[historical_symbol].is_empty() && row[historical_symbol] != record.symbol {
+                        historical_symbols.push((row[historical_symbol].clone(), i));
+                    }
+                    format!(
+                        "NameRecord {} name: \"{}\", symbol: {}, kind: NameKind::{}, from: {}, until: {} {}",
+                        "{",
+                        row[name],
+                        optional(&row[historical_symbol], &|s| format!("\"{}\"", s)),
+                        row[kind],
+                        optional(&row[from], &|s| s.to_owned()),
+                        optional(&row[until], &|s| s.to_owned()),
+                        "}"
+                    )
+                })
+                .collect()
+        },
+    );
+    historical_lowercase_names.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+    historical_lowercase_names.dedup();
+    out_file
+        .write_all(
+            format!(
+                "const LOWERCASE_HISTORICAL_NAMES_SORTED_ALPHABETICALLY: [(&str, u8); {}] = [",
+                historical_lowercase_names.len()
+            )
+            .as_bytes(),
+        )
+        .unwrap();
+    first = true;
+    for name in historical_lowercase_names {
+        if first {
+            first = false;
+        } else {
+            out_file.write_all(b", ").unwrap();
+        }
+        out_file
+            .write_all(format!("(\"{}\", {})", name.0, name.1).as_bytes())
+            .unwrap();
+    }
+    out_file.write_all(b"];\n").unwrap();
+    historical_symbols.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+    historical_symbols.dedup();
+    out_file
+        .write_all(
+            format!(
+                "const HISTORICAL_SYMBOLS_SORTED_ALPHABETICALLY: [(&str, u8); {}] = [",
+                historical_symbols.len()
+            )
+            .as_bytes(),
+        )
+        .unwrap();
+    first = true;
+    for symbol in historical_symbols {
+        if first {
+            first = false;
+        } else {
+            out_file.write_all(b", ").unwrap();
+        }
+        out_file
+            .write_all(format!("(\"{}\", {})", symbol.0, symbol.1).as_bytes())
+            .unwrap();
+    }
+    out_file.write_all(b"];\n").unwrap();
+    let localized_names = Table::load("localized_names.csv");
+    let locales = &localized_names.columns[1..];
+    // `Element::name_in` indexes these by the order of `Locale`
+    assert_eq!(
+        locales,
+        ["de", "fr", "es", "it", "pt", "ru", "zh", "ja"],
+        "the locale columns of localized_names.csv must follow the order of Locale"
+    );
+    out_file
+        .write_all(
+            format!(
+                "static LOCALIZED_NAMES: [[&str; 118]; {}] = [",
+                locales.len()
+            )
+            .as_bytes(),
+        )
+        .unwrap();
+    let mut sorted_names = Vec::with_capacity(locales.len());
+    for (li, locale) in locales.iter().enumerate() {
+        if li != 0 {
+            out_file.write_all(b", ").unwrap();
+        }
+        out_file.write_all(b"[").unwrap();
+        let mut names = Vec::with_capacity(118);
+        first = true;
+        for (i, record) in data.iter().enumerate() {
+            if first {
+                first = false;
+            } else {
+                out_file.write_all(b", ").unwrap();
+            }
+            let name = localized_names.get(&record.symbol, locale);
+            if name.is_empty() {
+                panic!("Missing {} name for {}", locale, record.symbol);
+            }
+            names.push((name.to_lowercase(), i));
+            out_file
+                .write_all(format!("\"{}\"", name).as_bytes())
+                .unwrap();
+        }
+        out_file.write_all(b"]").unwrap();
+        names.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+        sorted_names.push(names);
+    }
+    out_file.write_all(b"];\n").unwrap();
+    out_file
+        .write_all(
+            format!(
+                "static LOWERCASE_LOCALIZED_NAMES_SORTED_ALPHABETICALLY: [[(&str, u8); 118]; {}] = [",
+                locales.len()
+            )
+            .as_bytes(),
+        )
+        .unwrap();
+    for (li, names) in sorted_names.into_iter().enumerate() {
+        if li != 0 {
+            out_file.write_all(b", ").unwrap();
+        }
+        out_file.write_all(b"[").unwrap();
+        first = true;
+        for name in names {
+            if first {
+                first = false;
+            } else {
+                out_file.write_all(b", ").unwrap();
+            }
+            out_file
+                .write_all(format!("(\"{}\", {})", name.0, name.1).as_bytes())
+                .unwrap();
+        }
+        out_file.write_all(b"]").unwrap();
+    }
+    out_file.write_all(b"];\n").unwrap();
+    let discoveries = Table::load("discoveries.csv");
+    let str_slice = |v: &str| {
+        let items: Vec<String> = v
+            .split(';')
+            .filter(|i| !i.is_empty())
+            .map(|i| format!("\"{}\"", i))
+            .collect();
+        format!("&[{}]", items.join(", "))
+    };
+    out_file
+        .write_all(b"const DISCOVERIES: [Discovery; 118] = [")
+        .unwrap();
+    first = true;
+    for record in data.iter() {
+        if first {
+            first = false;
+        } else {
+            out_file.write_all(b", ").unwrap();
+        }
+        let identified = match discoveries.get(&record.symbol, "Identified") {
+            "Ancient" => "DiscoveryYear::Ancient".to_owned(),
+            "" => panic!("Missing discovery year for {}", record.symbol),
+            year => format!("DiscoveryYear::Year({})", year),
+        };
+        let isolated = match discoveries.get(&record.symbol, "Isolated") {
+            "" => "None".to_owned(),
+            year => format!("Some({})", year),
+        };
+        out_file
+            .write_all(
+                format!(
+                    "Discovery {} identified: {}, isolated: {}, discoverers: {}, countries: {}, laboratories: {}, etymology: \"{}\" {}",
+                    "{",
+                    identified,
+                    isolated,
+                    str_slice(discoveries.get(&record.symbol, "Discoverers")),
+                    str_slice(discoveries.get(&record.symbol, "Countries")),
+                    str_slice(discoveries.get(&record.symbol, "Laboratories")),
+                    discoveries.get(&record.symbol, "Etymology"),
+                    "}"
+                )
+                .as_bytes(),
+            )
+            .unwrap();
+    }
+    out_file.write_all(b"];\n").unwrap();
+    let crystal_structures = Table::load("crystal_structures.csv");
+    let column = |name| crystal_structures.column(name);
+    let float = |v: &str| {
+        if v.find('.').is_none() {
+            format!("{}.", v)
+        } else {
+            v.to_owned()
+        }
+    };
+    write_ranged_tables(
+        &mut out_file,
+        "CRYSTAL_STRUCTURES_DATA",
+        "CRYSTAL_STRUCTURES",
+        "CrystalStructure",
+        &data,
+        |_, record| {
+            crystal_structures
+                .rows_of(&record.symbol)
+                .map(|row| {
+                    format!(
+                        "CrystalStructure {} allotrope: \"{}\", system: CrystalSystem::{}, centering: Centering::{}, space_group: {}, space_group_symbol: \"{}\", lattice: LatticeConstants {} a: {}, b: {}, c: {}, alpha: {}, beta: {}, gamma: {} {} {}",
+                        "{",
+                        row[column("Allotrope")],
+                        row[column("System")],
+                        row[column("Centering")],
+                        row[column("SpaceGroup")],
+                        row[column("SpaceGroupSymbol")],
+                        "{",
+                        float(&row[column("A")]),
+                        float(&row[column("B")]),
+                        float(&row[column("C")]),
+                        float(&row[column("Alpha")]),
+                        float(&row[column("Beta")]),
+                        float(&row[column("Gamma")]),
+                        "}",
+                        "}"
+                    )
+                })
+                .collect()
+        },
+    );
+    let thermodynamics = Table::load("thermodynamics.csv");
+    write_f32_table(
+        &mut out_file,
+        "ENTHALPIES_OF_FUSION",
+        &data,
+        &thermodynamics,
+        "EnthalpyOfFusion",
+    );
+    write_f32_table(
+        &mut out_file,
+        "ENTHALPIES_OF_VAPORIZATION",
+        &data,
+        &thermodynamics,
+        "EnthalpyOfVaporization",
+    );
+    write_f32_table(
+        &mut out_file,
+        "MOLAR_HEAT_CAPACITIES",
+        &data,
+        &thermodynamics,
+        "MolarHeatCapacity",
+    );
+    write_f32_table(
+        &mut out_file,
+        "SPECIFIC_HEATS",
+        &data,
+        &thermodynamics,
+        "SpecificHeat",
+    );
+    write_f32_table(
+        &mut out_file,
+        "THERMAL_CONDUCTIVITIES",
+        &data,
+        &thermodynamics,
+        "ThermalConductivity",
+    );
+    write_f32_table(
+        &mut out_file,
+        "STANDARD_ENTROPIES",
+        &data,
+        &thermodynamics,
+        "StandardEntropy",
+    );
+    let electromagnetism = Table::load("electromagnetism.csv");
+    write_f32_table(
+        &mut out_file,
+        "ELECTRICAL_RESISTIVITIES",
+        &data,
+        &electromagnetism,
+        "Resistivity",
+    );
+    write_f32_table(
+        &mut out_file,
+        "SUPERCONDUCTING_TEMPERATURES",
+        &data,
+        &electromagnetism,
+        "SuperconductingTemperature",
+    );
+    out_file
+        .write_all(b"const MAGNETIC_ORDERINGS: [Option<MagneticOrdering>; 118] = [")
+        .unwrap();
+    first = true;
+    for record in data.iter() {
+        if first {
+            first = false;
+        } else {
+            out_file.write_all(b", ").unwrap();
+        }
+        let ordering = electromagnetism.get(&record.symbol, "MagneticOrdering");
+        if ordering.is_empty() {
+            out_file.write_all(b"None").unwrap();
+        } else {
+            out_file
+                .write_all(format!("Some(MagneticOrdering::{})", ordering).as_bytes())
+                .unwrap();
+        }
+    }
+    out_file.write_all(b"];\n").unwrap();
+    // Susceptibilities can be negative, so zero cannot mark missing values
+    out_file
+        .write_all(b"const MOLAR_MAGNETIC_SUSCEPTIBILITIES: [Option<f32>; 118] = [")
+        .unwrap();
+    first = true;
+    for record in data.iter() {
+        if first {
+            first = false;
+        } else {
+            out_file.write_all(b", ").unwrap();
+        }
+        let susceptibility = electromagnetism.get(&record.symbol, "MolarSusceptibility");
+        if susceptibility.is_empty() {
+            out_file.write_all(b"None").unwrap();
+        } else {
+            out_file
+                .write_all(format!("Some({})", susceptibility).as_bytes())
+                .unwrap();
+        }
+    }
+    out_file.write_all(b"];\n").unwrap();
+    let abundances = Table::load("abundances.csv");
+    write_f32_table(
+        &mut out_file,
+        "CRUSTAL_ABUNDANCES",
+        &data,
+        &abundances,
+        "Crust",
+    );
+    write_f32_table(
+        &mut out_file,
+        "SEAWATER_ABUNDANCES",
+        &data,
+        &abundances,
+        "Seawater",
+    );
+    write_f32_table(
+        &mut out_file,
+        "SOLAR_ABUNDANCES",
+        &data,
+        &abundances,
+        "Solar",
+    );
+    write_f32_table(
+        &mut out_file,
+        "HUMAN_BODY_ABUNDANCES",
+        &data,
+        &abundances,
+        "HumanBody",
+    );
+    let isotopes = Table::load("isotopes.csv");
+    let mass_number = isotopes.column("MassNumber");
+    let half_life = isotopes.column("HalfLife");
+    let mass = isotopes.column("Mass");
+    let abundance = isotopes.column("Abundance");
+    // Half-lives are written with a unit, e.g. "4.468e9 y" or "22.00 min"
+    let seconds = |v: &str| {
+        let mut parts = v.split(' ');
+        let value: f64 = parts.next().unwrap().parse().unwrap();
+        let unit = match parts.next().unwrap() {
+            "us" => 1e-6,
+            "ms" => 1e-3,
+            "s" => 1.,
+            "min" => 60.,
+            "h" => 3600.,
+            "d" => 86400.,
+            // As in NUBASE, 1 y = 365.2422 d
+            "y" => 31556926.,
+            unit => panic!("Unknown half-life unit: {}", unit),
+        };
+        format!("{:e}", value * unit)
+    };
+    // Isotopes in the order of ISOTOPES_DATA, with the index of their element
+    let mut all_isotopes = Vec::with_capacity(isotopes.rows.len());
+    for (i, record) in data.iter().enumerate() {
+        for row in isotopes.rows_of(&record.symbol) {
+            all_isotopes.push((i, row));
+        }
+    }
+    let isotope_index = |element: usize, a: &str| {
+        all_isotopes
+            .iter()
+            .position(|(i, row)| *i == element && row[mass_number] == a)
+    };
+    let decays = Table::load("decays.csv");
+    let decay_mass_number = decays.column("MassNumber");
+    let mode = decays.column("Mode");
+    let branching_ratio = decays.column("BranchingRatio");
+    out_file
+        .write_all(
+            format!(
+                "static DECAYS_DATA: [(DecayMode, f64, u16); {}] = [",
+                decays.rows.len()
+            )
+            .as_bytes(),
+        )
+        .unwrap();
+    first = true;
+    oi = 0;
+    let mut decays_ranges = Vec::with_capacity(all_isotopes.len());
+    for (i, isotope) in all_isotopes.iter() {
+        let mut len = 0;
+        for row in decays
+            .rows_of(&data[*i].symbol)
+            .filter(|row| row[decay_mass_number] == isotope[mass_number])
+        {
+            if first {
+                first = false;
+            } else {
+                out_file.write_all(b", ").unwrap();
+            }
+            let a: i32 = isotope[mass_number].parse().unwrap();
+            let (dz, da) = match &row[mode][..] {
+                "Alpha" => (-2, -4),
+                "BetaMinus" => (1, 0),
+                "BetaPlus" | "ElectronCapture" => (-1, 0),
+                mode => panic!("Unknown decay mode: {}", mode),
+            };
+            let daughter = isotope_index((*i as i32 + dz) as usize, &(a + da).to_string())
+                .unwrap_or_else(|| {
+                    panic!(
+                        "Missing daughter of {}-{} by {}",
+                        data[*i].symbol, a, row[mode]
+                    )
+                });
+            let ratio = &row[branching_ratio];
+            out_file
+                .write_all(
+                    format!(
+                        "(DecayMode::{}, {}{}, {})",
+                        row[mode],
+                        ratio,
+                        if ratio.find('.').is_none() { "." } else { "" },
+                        daughter
+                    )
+                    .as_bytes(),
+                )
+                .unwrap();
+            len += 1;
+        }
+        decays_ranges.push((oi, len));
+        oi += len;
+    }
+    out_file.write_all(b"];\n").unwrap();
+    write_ranged_tables(
+        &mut out_file,
+        "ISOTOPES_DATA",
+        "ISOTOPES",
+        "Isotope",
+        &data,
+        |i, record| {
+            all_isotopes
+                .iter()
+                .zip(decays_ranges.iter())
+                .filter(|((e, _), _)| *e == i)
+                .map(|((_, row), (decays_start, decays_len))| {
+                    format!(
+                        "Isotope {} element: Element::{}, mass_number: {}, half_life: {}, mass: {}, abundance: {}, decays: ({}, {}) {}",
+                        "{",
+                        record.name,
+                        row[mass_number],
+                        optional(&row[half_life], &seconds),
+                        optional(&row[mass], &float),
+                        optional(&row[abundance], &float),
+                        decays_start,
+                        decays_len,
+                        "}"
+                    )
+                })
+                .collect()
+        },
+    );
+    let hazards = Table::load("hazards.csv");
+    let pictograms = hazards.column("Pictograms");
+    let hazard_statements = hazards.column("HazardStatements");
+    let nfpa_704 = hazards.column("Nfpa704");
+    let source = hazards.column("Source");
+    let list = |v: &str, wrap: &dyn Fn(&str) -> String| {
+        v.split(';')
+            .filter(|s| !s.is_empty())
+            .map(wrap)
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    out_file
+        .write_all(b"static HAZARDS: [Option<Hazards>; 118] = [")
+        .unwrap();
+    first = true;
+    for record in data.iter() {
+        if first {
+            first = false;
+        } else {
+            out_file.write_all(b", ").unwrap();
+        }
+        let row = match hazards.rows_of(&record.symbol).next() {
+            Some(row) => row,
+            None => {
+                out_file.write_all(b"None").unwrap();
+                continue;
+            }
+        };
+        // The diamond is written as "health flammability instability [special]"
+        let diamond = optional(&row[nfpa_704], &|v| {
+            let parts: Vec<&str> = v.split(' ').collect();
+            format!(
+                "Nfpa704 {} health: {}, flammability: {}, instability: {}, special: {} {}",
+                "{",
+                parts[0],
+                parts[1],
+                parts[2],
+                match parts.get(3) {
+                    None => "None",
+                    Some(&"W") => "Some(NfpaSpecial::WaterReactive)",
+                    Some(&"OX") => "Some(NfpaSpecial::Oxidizer)",
+                    Some(&"SA") => "Some(NfpaSpecial::SimpleAsphyxiant)",
+                    Some(special) => panic!("Unknown NFPA 704 special hazard: {}", special),
+                },
+                "}"
+            )
+        });
+        out_file
+            .write_all(
+                format!(
+                    "Some(Hazards {} pictograms: &[{}], hazard_statements: &[{}], nfpa_704: {}, source: HazardSource::{} {})",
+                    "{",
+                    list(&row[pictograms], &|p| {
+                        let pictogram = match p {
+                            "GHS01" => "Explosive",
+                            "GHS02" => "Flammable",
+                            "GHS03" => "Oxidizing",
+                            "GHS04" => "CompressedGas",
+                            "GHS05" => "Corrosive",
+                            "GHS06" => "AcuteToxicity",
+                            "GHS07" => "Harmful",
+                            "GHS08" => "HealthHazard",
+                            "GHS09" => "EnvironmentalHazard",
+                            p => panic!("Unknown GHS pictogram: {}", p),
+                        };
+                        format!("GhsPictogram::{}", pictogram)
+                    }),
+                    list(&row[hazard_statements], &|h| format!("\"{}\"", h)),
+                    diamond,
+                    match row[source].as_str() {
+                        "Harmonised" | "Supplier" => &row[source],
+                        s => panic!("Unknown hazard source: {}", s),
+                    },
+                    "}"
+                )
+                .as_bytes(),
+            )
+            .unwrap();
+    }
+    out_file.write_all(b"];\n").unwrap();
+    let identifiers = Table::load("identifiers.csv");
+    write_identifier_tables(
+        &mut out_file,
+        "CAS_NUMBERS",
+        &data,
+        &identifiers,
+        "CAS",
+        false,
+    );
+    write_identifier_tables(
+        &mut out_file,
+        "PUBCHEM_CIDS",
+        &data,
+        &identifiers,
+        "PubChemCID",
+        true,
+    );
+    write_identifier_tables(
+        &mut out_file,
+        "CHEBI_IDS",
+        &data,
+        &identifiers,
+        "ChEBI",
+        true,
+    );
+    write_identifier_tables(&mut out_file, "INCHIS", &data, &identifiers, "InChI", false);
+    write_identifier_tables(
+        &mut out_file,
+        "INCHI_KEYS",
+        &data,
+        &identifiers,
+        "InChIKey",
+        false,
+    );
+    write_identifier_tables(
+        &mut out_file,
+        "WIKIDATA_IDS",
+        &data,
+        &identifiers,
+        "Wikidata",
+        false,
+    );
+    let spectral_lines = Table::load("spectral_lines.csv");
+    let wavelength = spectral_lines.column("Wavelength");
+    let intensity = spectral_lines.column("Intensity");
+    let ionization_stage = spectral_lines.column("IonizationStage");
+    let flame = spectral_lines.column("Flame");
+    write_ranged_tables(
+        &mut out_file,
+        "SPECTRAL_LINES_DATA",
+        "SPECTRAL_LINES",
+        "SpectralLine",
+        &data,
+        |_, record| {
+            spectral_lines
+                .rows_of(&record.symbol)
+                .map(|row| {
+                    format!(
+                        "SpectralLine {} element: Element::{}, wavelength: {}, intensity: {}, ionization_stage: {}, in_flame: {} {}",
+                        "{",
+                        record.name,
+                        float(&row[wavelength]),
+                        row[intensity],
+                        row[ionization_stage],
+                        row[flame] == "y",
+                        "}"
+                    )
+                })
+                .collect()
+        },
+    );
+    let colors = Table::load("colors.csv");
+    write_color_table(&mut out_file, "JMOL_COLORS", &data, &colors, "Jmol");
+    write_color_table(&mut out_file, "RASMOL_COLORS", &data, &colors, "RasMol");
+    write_color_table(&mut out_file, "PYMOL_COLORS", &data, &colors, "PyMOL");
 }
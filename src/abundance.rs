@@ -0,0 +1,78 @@
+use crate::{
+    known, periodic_table, Element, CRUSTAL_ABUNDANCES, HUMAN_BODY_ABUNDANCES, SEAWATER_ABUNDANCES,
+    SOLAR_ABUNDANCES,
+};
+
+/// Where the abundance of an element is measured.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Reservoir {
+    /// Earth's continental crust.
+    EarthCrust,
+    /// Ocean water, taking its density as 1 kg/L.
+    Seawater,
+    /// The solar photosphere, completed with meteoritic values for elements
+    /// without reliable photospheric measurements.
+    Solar,
+    HumanBody,
+}
+
+impl Reservoir {
+    pub const ALL: [Reservoir; 4] = [
+        Reservoir::EarthCrust,
+        Reservoir::Seawater,
+        Reservoir::Solar,
+        Reservoir::HumanBody,
+    ];
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Abundance {
+    pub reservoir: Reservoir,
+    /// Fraction of the total mass of the reservoir, between 0 and 1.
+    pub mass_fraction: f32,
+}
+
+impl Abundance {
+    #[inline(always)]
+    pub fn percent(&self) -> f32 {
+        self.mass_fraction * 1e2
+    }
+
+    /// Parts per million by mass (mg/kg).
+    #[inline(always)]
+    pub fn ppm(&self) -> f32 {
+        self.mass_fraction * 1e6
+    }
+
+    /// Parts per billion by mass (µg/kg).
+    #[inline(always)]
+    pub fn ppb(&self) -> f32 {
+        self.mass_fraction * 1e9
+    }
+}
+
+impl Element {
+    /// Returns `None` if the element has not been measured in the reservoir,
+    /// which for most synthetic elements means it is not there at all.
+    pub fn get_abundance(&self, reservoir: Reservoir) -> Option<Abundance> {
+        let table = match reservoir {
+            Reservoir::EarthCrust => &CRUSTAL_ABUNDANCES,
+            Reservoir::Seawater => &SEAWATER_ABUNDANCES,
+            Reservoir::Solar => &SOLAR_ABUNDANCES,
+            Reservoir::HumanBody => &HUMAN_BODY_ABUNDANCES,
+        };
+        known(table[*self as usize]).map(|mass_fraction| Abundance {
+            reservoir,
+            mass_fraction,
+        })
+    }
+}
+
+/// The elements with a known abundance in `reservoir`, most abundant first.
+pub fn elements_by_abundance(reservoir: Reservoir) -> Vec<(Element, Abundance)> {
+    let mut elements: Vec<_> = periodic_table()
+        .filter_map(|e| e.get_abundance(reservoir).map(|a| (e, a)))
+        .collect();
+    elements.sort_by(|(_, a), (_, b)| b.mass_fraction.total_cmp(&a.mass_fraction));
+    elements
+}
@@ -0,0 +1,161 @@
+//! Parsing of chemical formulas such as "H2SO4", "Ca(OH)2", "K4[Fe(CN)6]" or
+//! "CuSO4·5H2O".
+
+use periodic_table_on_an_enum::Element;
+
+type Counts = Vec<(Element, u32)>;
+
+/// Adds `factor` times the counts of `other` to `counts`, keeping the order
+/// in which the elements first appear.
+fn add(counts: &mut Counts, other: &[(Element, u32)], factor: u32) -> Result<(), String> {
+    for &(element, n) in other {
+        let n = n
+            .checked_mul(factor)
+            .ok_or_else(|| "too many atoms".to_owned())?;
+        match counts.iter_mut().find(|(e, _)| *e == element) {
+            Some((_, count)) => {
+                *count = count
+                    .checked_add(n)
+                    .ok_or_else(|| "too many atoms".to_owned())?
+            }
+            None => counts.push((element, n)),
+        }
+    }
+    Ok(())
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn error(&self, message: &str) -> String {
+        format!("{} at position {}", message, self.pos + 1)
+    }
+
+    fn number(&mut self) -> Result<Option<u32>, String> {
+        let start = self.pos;
+        while matches!(self.peek(), Some(c) if c.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        if start == self.pos {
+            return Ok(None);
+        }
+        let digits: String = self.chars[start..self.pos].iter().collect();
+        match digits.parse() {
+            Ok(0) | Err(_) => {
+                self.pos = start;
+                Err(self.error("invalid count"))
+            }
+            Ok(n) => Ok(Some(n)),
+        }
+    }
+
+    fn element(&mut self) -> Result<Element, String> {
+        let start = self.pos;
+        self.pos += 1;
+        while self.pos - start < 2 && matches!(self.peek(), Some(c) if c.is_ascii_lowercase()) {
+            self.pos += 1;
+        }
+        let symbol: String = self.chars[start..self.pos].iter().collect();
+        Element::from_symbol(&symbol).ok_or_else(|| {
+            self.pos = start;
+            self.error(&format!("unknown element \"{}\"", symbol))
+        })
+    }
+
+    /// Parses elements and bracketed groups, each with an optional count,
+    /// until a character that cannot start one.
+    fn sequence(&mut self) -> Result<Counts, String> {
+        let mut counts = Vec::new();
+        loop {
+            match self.peek() {
+                Some(c) if c.is_ascii_uppercase() => {
+                    let element = self.element()?;
+                    let n = self.number()?.unwrap_or(1);
+                    add(&mut counts, &[(element, n)], 1)?;
+                }
+                Some(open @ '(') | Some(open @ '[') => {
+                    let close = if open == '(' { ')' } else { ']' };
+                    self.pos += 1;
+                    let inner = self.sequence()?;
+                    if inner.is_empty() {
+                        return Err(self.error("empty group"));
+                    }
+                    if self.peek() != Some(close) {
+                        return Err(self.error(&format!("expected '{}'", close)));
+                    }
+                    self.pos += 1;
+                    let n = self.number()?.unwrap_or(1);
+                    add(&mut counts, &inner, n)?;
+                }
+                _ => return Ok(counts),
+            }
+        }
+    }
+}
+
+/// The number of atoms of each element, in the order in which they first
+/// appear. Parts separated by '·', '.' or '*' (as in hydrates) can start
+/// with a multiplier.
+pub fn parse(formula: &str) -> Result<Counts, String> {
+    let mut parser = Parser {
+        chars: formula.trim().chars().collect(),
+        pos: 0,
+    };
+    let mut counts = Vec::new();
+    loop {
+        let factor = parser.number()?.unwrap_or(1);
+        let part = parser.sequence()?;
+        if part.is_empty() {
+            return Err(parser.error("expected an element"));
+        }
+        add(&mut counts, &part, factor)?;
+        match parser.peek() {
+            None => return Ok(counts),
+            Some('·') | Some('.') | Some('*') => parser.pos += 1,
+            Some(c) => return Err(parser.error(&format!("unexpected '{}'", c))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse;
+    use periodic_table_on_an_enum::Element::*;
+
+    #[test]
+    fn groups() {
+        assert_eq!(
+            parse("Ca(OH)2"),
+            Ok(vec![(Calcium, 1), (Oxygen, 2), (Hydrogen, 2)])
+        );
+        assert_eq!(
+            parse("K4[Fe(CN)6]"),
+            Ok(vec![(Potassium, 4), (Iron, 1), (Carbon, 6), (Nitrogen, 6)])
+        );
+    }
+
+    #[test]
+    fn hydrates() {
+        let expected = vec![(Copper, 1), (Sulfur, 1), (Oxygen, 9), (Hydrogen, 10)];
+        assert_eq!(parse("CuSO4·5H2O"), Ok(expected.clone()));
+        assert_eq!(parse("CuSO4.5H2O"), Ok(expected));
+    }
+
+    #[test]
+    fn errors() {
+        assert!(parse("").is_err());
+        assert_eq!(parse("H2()"), Err("empty group at position 4".to_owned()));
+        assert_eq!(parse("H0"), Err("invalid count at position 2".to_owned()));
+        assert!(parse("Ca(OH2").is_err());
+        assert!(parse("CaOH)2").is_err());
+        assert!(parse("K4[Fe(CN)6)").is_err());
+        assert!(parse("Xx").is_err());
+    }
+}
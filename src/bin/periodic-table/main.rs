@@ -0,0 +1,358 @@
+//! Command-line access to the periodic table.
+
+mod formula;
+mod output;
+
+use output::{Format, Records, Value};
+use periodic_table_on_an_enum::{
+    periodic_table, DiscoveryYear, Element, StateOfMatter, SubshellKind,
+};
+use std::env;
+use std::process;
+
+const USAGE: &str = "\
+Usage: periodic-table [--format json|csv|table] <command>
+
+Commands:
+  show <element>               Properties of an element, given by symbol, name
+                               or atomic number
+  list [--block s|p|d|f] [--state solid|liquid|gas] [--period <n>] [--group <n>]
+                               Elements matching all the given filters
+  sort --by <property> [--reverse]
+                               Elements by increasing value of a property,
+                               those without a known value last
+  formula mass <formula>       Molar mass in g/mol, e.g. of H2SO4 or CuSO4·5H2O
+  formula composition <formula>
+                               Number of atoms and mass percent of each element
+
+Properties:
+  atomic-number, atomic-mass (u), electronegativity (Pauling),
+  atomic-radius (pm), ionization-energy (eV), electron-affinity (eV),
+  melting-point (K), boiling-point (K), density (g/cm³), year-discovered
+";
+
+type Property = fn(Element) -> Option<String>;
+
+fn positive<T: PartialOrd + Default + ToString>(value: T) -> Option<String> {
+    if value > T::default() {
+        Some(value.to_string())
+    } else {
+        None
+    }
+}
+
+/// The numeric properties, which can be sorted by. Unknown values are
+/// `None`, and the year of discovery of the elements known since antiquity
+/// is "ancient", which sorts before every year.
+const PROPERTIES: [(&str, Property); 10] = [
+    ("atomic_number", |e| Some(e.get_atomic_number().to_string())),
+    ("atomic_mass", |e| positive(e.get_atomic_mass())),
+    ("electronegativity", |e| {
+        e.get_electronegativity_checked().map(|x| x.to_string())
+    }),
+    ("atomic_radius", |e| positive(e.get_atomic_radius())),
+    ("ionization_energy", |e| positive(e.get_ionization_energy())),
+    ("electron_affinity", |e| positive(e.get_electron_affinity())),
+    ("melting_point", |e| positive(e.get_melting_point())),
+    ("boiling_point", |e| positive(e.get_boiling_point())),
+    ("density", |e| positive(e.get_density())),
+    ("year_discovered", |e| match e.get_discovery().identified {
+        DiscoveryYear::Ancient => Some("ancient".to_owned()),
+        DiscoveryYear::Year(year) => Some(year.to_string()),
+    }),
+];
+
+fn property(name: &str) -> Result<(&'static str, Property), String> {
+    let name = name.replace('-', "_");
+    PROPERTIES
+        .iter()
+        .find(|(n, _)| *n == name)
+        .copied()
+        .ok_or_else(|| format!("unknown property \"{}\"", name.replace('_', "-")))
+}
+
+fn state_name(state: StateOfMatter) -> &'static str {
+    match state {
+        StateOfMatter::Solid => "solid",
+        StateOfMatter::Liquid => "liquid",
+        StateOfMatter::Gas => "gas",
+    }
+}
+
+fn block_name(block: SubshellKind) -> &'static str {
+    match block {
+        SubshellKind::S => "s",
+        SubshellKind::P => "p",
+        SubshellKind::D => "d",
+        SubshellKind::F => "f",
+        SubshellKind::G => "g",
+    }
+}
+
+/// Accepts a symbol, a name (ignoring case) or an atomic number.
+fn parse_element(text: &str) -> Result<Element, String> {
+    if let Ok(z) = text.parse() {
+        return Element::from_atomic_number(z).ok_or_else(|| format!("no element has Z = {}", z));
+    }
+    let mut symbol = text.to_ascii_lowercase();
+    if let Some(first) = symbol.get_mut(..1) {
+        first.make_ascii_uppercase();
+    }
+    Element::from_symbol(text)
+        .or_else(|| Element::from_symbol(&symbol))
+        .or_else(|| Element::from_name_case_insensitive(text))
+        .ok_or_else(|| format!("unknown element \"{}\"", text))
+}
+
+/// Removes `--name value` or `--name=value` from the arguments.
+fn take_option(args: &mut Vec<String>, name: &str) -> Result<Option<String>, String> {
+    let prefix = format!("{}=", name);
+    if let Some(i) = args.iter().position(|a| a.starts_with(&prefix)) {
+        return Ok(Some(args.remove(i)[prefix.len()..].to_owned()));
+    }
+    match args.iter().position(|a| a == name) {
+        Some(i) if i + 1 < args.len() => {
+            args.remove(i);
+            Ok(Some(args.remove(i)))
+        }
+        Some(_) => Err(format!("{} needs a value", name)),
+        None => Ok(None),
+    }
+}
+
+/// Removes `--name` from the arguments.
+fn take_flag(args: &mut Vec<String>, name: &str) -> bool {
+    match args.iter().position(|a| a == name) {
+        Some(i) => {
+            args.remove(i);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Checks that the command used all of its arguments.
+fn no_more(args: &[String]) -> Result<(), String> {
+    match args.first() {
+        Some(arg) => Err(format!("unexpected argument \"{}\"", arg)),
+        None => Ok(()),
+    }
+}
+
+/// A value that is only known for some elements.
+fn optional(value: Option<String>, number: bool) -> Value {
+    match value {
+        Some(value) if number => Value::Number(value),
+        Some(value) => Value::Text(value),
+        None => Value::Missing,
+    }
+}
+
+/// A value of one of `PROPERTIES`, which is a number unless it is "ancient".
+fn property_value(value: Option<String>) -> Value {
+    let number = match &value {
+        Some(v) => v.parse::<f64>().is_ok(),
+        None => true,
+    };
+    optional(value, number)
+}
+
+fn show(mut args: Vec<String>) -> Result<Records, String> {
+    if args.is_empty() {
+        return Err("show needs an element".to_owned());
+    }
+    let element = parse_element(&args.remove(0))?;
+    no_more(&args)?;
+    let mut columns = vec![
+        "atomic_number",
+        "symbol",
+        "name",
+        "group_block",
+        "period",
+        "group",
+        "block",
+        "standard_state",
+        "electron_configuration",
+    ];
+    let mut row = vec![
+        Value::number(element.get_atomic_number()),
+        Value::text(element.get_symbol()),
+        Value::text(element.get_name()),
+        Value::text(element.get_group().name()),
+        Value::number(element.get_period()),
+        optional(element.get_group_number().map(|g| g.to_string()), true),
+        Value::text(block_name(element.get_block())),
+        Value::text(state_name(element.get_standard_state())),
+        Value::text(element.get_electronic_configuration_str()),
+    ];
+    for (name, property) in PROPERTIES.iter().skip(1) {
+        columns.push(name);
+        row.push(property_value(property(element)));
+    }
+    columns.push("cas_number");
+    row.push(optional(element.get_cas_number().map(str::to_owned), false));
+    let mut records = Records::new(columns);
+    records.rows.push(row);
+    Ok(records)
+}
+
+fn element_columns(element: Element) -> Vec<Value> {
+    vec![
+        Value::number(element.get_atomic_number()),
+        Value::text(element.get_symbol()),
+        Value::text(element.get_name()),
+    ]
+}
+
+fn list(mut args: Vec<String>) -> Result<Records, String> {
+    let block = take_option(&mut args, "--block")?;
+    let state = take_option(&mut args, "--state")?;
+    let parse_number = |option: Option<String>, name: &str| -> Result<Option<u8>, String> {
+        option
+            .map(|n| n.parse().map_err(|_| format!("invalid {} \"{}\"", name, n)))
+            .transpose()
+    };
+    let period = parse_number(take_option(&mut args, "--period")?, "period")?;
+    let group = parse_number(take_option(&mut args, "--group")?, "group")?;
+    no_more(&args)?;
+    if let Some(block) = &block {
+        if !["s", "p", "d", "f"].contains(&block.as_str()) {
+            return Err(format!("unknown block \"{}\"", block));
+        }
+    }
+    if let Some(state) = &state {
+        if !["solid", "liquid", "gas"].contains(&state.as_str()) {
+            return Err(format!("unknown state \"{}\"", state));
+        }
+    }
+    let mut records = Records::new(vec!["atomic_number", "symbol", "name"]);
+    records.rows = periodic_table()
+        .filter(|e| match &block {
+            Some(b) => block_name(e.get_block()) == b,
+            None => true,
+        })
+        .filter(|e| match &state {
+            Some(s) => state_name(e.get_standard_state()) == s,
+            None => true,
+        })
+        .filter(|e| match period {
+            Some(p) => e.get_period() == p,
+            None => true,
+        })
+        .filter(|e| match group {
+            Some(g) => e.get_group_number() == Some(g),
+            None => true,
+        })
+        .map(element_columns)
+        .collect();
+    Ok(records)
+}
+
+fn sort(mut args: Vec<String>) -> Result<Records, String> {
+    let by = take_option(&mut args, "--by")?.ok_or_else(|| "sort needs --by".to_owned())?;
+    let reverse = take_flag(&mut args, "--reverse");
+    no_more(&args)?;
+    let (name, property) = property(&by)?;
+    let mut elements: Vec<(Element, Option<String>)> =
+        periodic_table().map(|e| (e, property(e))).collect();
+    let key = |value: &Option<String>| {
+        value
+            .as_ref()
+            .map(|v| v.parse::<f64>().unwrap_or(f64::NEG_INFINITY))
+    };
+    elements.sort_by(|(_, a), (_, b)| match (key(a), key(b)) {
+        (Some(a), Some(b)) if reverse => b.total_cmp(&a),
+        (Some(a), Some(b)) => a.total_cmp(&b),
+        (a, b) => b.is_some().cmp(&a.is_some()),
+    });
+    let mut records = Records::new(vec!["atomic_number", "symbol", "name", name]);
+    records.rows = elements
+        .into_iter()
+        .map(|(e, value)| {
+            let mut row = element_columns(e);
+            row.push(property_value(value));
+            row
+        })
+        .collect();
+    Ok(records)
+}
+
+/// Rounds to four decimals and removes the trailing zeros.
+fn round(value: f64) -> String {
+    let text = format!("{:.4}", value);
+    text.trim_end_matches('0').trim_end_matches('.').to_owned()
+}
+
+fn formula(mut args: Vec<String>) -> Result<(Records, bool), String> {
+    if args.len() < 2 {
+        return Err("formula needs a subcommand and a formula".to_owned());
+    }
+    let subcommand = args.remove(0);
+    let text = args.remove(0);
+    no_more(&args)?;
+    let counts = formula::parse(&text).map_err(|e| format!("invalid formula: {}", e))?;
+    let molar_mass: f64 = counts
+        .iter()
+        .map(|&(e, n)| e.get_atomic_mass() * n as f64)
+        .sum();
+    match subcommand.as_str() {
+        "mass" => {
+            let mut records = Records::new(vec!["formula", "molar_mass"]);
+            records
+                .rows
+                .push(vec![Value::text(text), Value::Number(round(molar_mass))]);
+            Ok((records, true))
+        }
+        "composition" => {
+            let mut records = Records::new(vec!["symbol", "count", "mass_percent"]);
+            records.rows = counts
+                .iter()
+                .map(|&(e, n)| {
+                    let percent = 100.0 * e.get_atomic_mass() * n as f64 / molar_mass;
+                    vec![
+                        Value::text(e.get_symbol()),
+                        Value::number(n),
+                        Value::Number(round(percent)),
+                    ]
+                })
+                .collect();
+            Ok((records, false))
+        }
+        _ => Err(format!("unknown formula subcommand \"{}\"", subcommand)),
+    }
+}
+
+fn run(mut args: Vec<String>) -> Result<String, String> {
+    if args.is_empty() || args.iter().any(|a| a == "-h" || a == "--help") {
+        return Ok(USAGE.to_owned());
+    }
+    let format = match take_option(&mut args, "--format")? {
+        Some(name) => {
+            Format::from_name(&name).ok_or_else(|| format!("unknown format \"{}\"", name))?
+        }
+        None => Format::Table,
+    };
+    if args.is_empty() {
+        return Err("missing command".to_owned());
+    }
+    let command = args.remove(0);
+    let (records, single) = match command.as_str() {
+        "show" => (show(args)?, true),
+        "list" => (list(args)?, false),
+        "sort" => (sort(args)?, false),
+        "formula" => formula(args)?,
+        _ => return Err(format!("unknown command \"{}\"", command)),
+    };
+    Ok(records.render(format, single))
+}
+
+fn main() {
+    match run(env::args().skip(1).collect()) {
+        Ok(output) => print!("{}", output),
+        Err(error) => {
+            eprintln!("periodic-table: {}", error);
+            eprintln!("Try 'periodic-table --help' for more information.");
+            process::exit(2);
+        }
+    }
+}
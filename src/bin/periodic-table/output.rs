@@ -0,0 +1,182 @@
+//! Printing of the results as JSON, CSV or an aligned table.
+
+use std::fmt::Write;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Format {
+    Json,
+    Csv,
+    Table,
+}
+
+impl Format {
+    pub fn from_name(name: &str) -> Option<Format> {
+        match name {
+            "json" => Some(Format::Json),
+            "csv" => Some(Format::Csv),
+            "table" => Some(Format::Table),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// Kept as text, as printed by the type it comes from, to avoid showing
+    /// `f32` values with spurious digits.
+    Number(String),
+    Text(String),
+    Missing,
+}
+
+impl Value {
+    pub fn number<T: ToString>(value: T) -> Value {
+        Value::Number(value.to_string())
+    }
+
+    pub fn text<T: ToString>(value: T) -> Value {
+        Value::Text(value.to_string())
+    }
+
+    fn to_json(&self) -> String {
+        match self {
+            Value::Number(number) => number.clone(),
+            Value::Text(text) => {
+                let mut json = String::from("\"");
+                for c in text.chars() {
+                    match c {
+                        '"' => json.push_str("\\\""),
+                        '\\' => json.push_str("\\\\"),
+                        '\n' => json.push_str("\\n"),
+                        c if (c as u32) < 0x20 => write!(json, "\\u{:04x}", c as u32).unwrap(),
+                        c => json.push(c),
+                    }
+                }
+                json.push('"');
+                json
+            }
+            Value::Missing => "null".to_owned(),
+        }
+    }
+
+    fn to_csv(&self) -> String {
+        match self {
+            Value::Text(text) if text.contains(&[',', '"', '\n'][..]) => {
+                format!("\"{}\"", text.replace('"', "\"\""))
+            }
+            Value::Number(text) | Value::Text(text) => text.clone(),
+            Value::Missing => String::new(),
+        }
+    }
+
+    fn to_table(&self) -> String {
+        match self {
+            Value::Number(text) | Value::Text(text) => text.clone(),
+            Value::Missing => "-".to_owned(),
+        }
+    }
+}
+
+/// Rows of values under named columns.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Records {
+    pub columns: Vec<&'static str>,
+    pub rows: Vec<Vec<Value>>,
+}
+
+impl Records {
+    pub fn new(columns: Vec<&'static str>) -> Records {
+        Records {
+            columns,
+            rows: Vec::new(),
+        }
+    }
+
+    /// A single record is printed as a JSON object rather than an array,
+    /// and as one line per column in a table.
+    pub fn render(&self, format: Format, single: bool) -> String {
+        match format {
+            Format::Json => self.render_json(single),
+            Format::Csv => self.render_csv(),
+            Format::Table if single => self.render_fields(),
+            Format::Table => self.render_table(),
+        }
+    }
+
+    fn render_json(&self, single: bool) -> String {
+        let objects: Vec<String> = self
+            .rows
+            .iter()
+            .map(|row| {
+                let fields: Vec<String> = self
+                    .columns
+                    .iter()
+                    .zip(row.iter())
+                    .map(|(column, value)| format!("\"{}\": {}", column, value.to_json()))
+                    .collect();
+                format!("{{{}}}", fields.join(", "))
+            })
+            .collect();
+        if single && objects.len() == 1 {
+            format!("{}\n", objects[0])
+        } else {
+            format!("[{}]\n", objects.join(",\n "))
+        }
+    }
+
+    fn render_csv(&self) -> String {
+        let mut csv = self.columns.join(",");
+        csv.push('\n');
+        for row in self.rows.iter() {
+            let cells: Vec<String> = row.iter().map(Value::to_csv).collect();
+            csv.push_str(&cells.join(","));
+            csv.push('\n');
+        }
+        csv
+    }
+
+    fn render_table(&self) -> String {
+        let cells: Vec<Vec<String>> = self
+            .rows
+            .iter()
+            .map(|row| row.iter().map(Value::to_table).collect())
+            .collect();
+        let widths: Vec<usize> = self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(i, column)| {
+                cells
+                    .iter()
+                    .map(|row| row[i].chars().count())
+                    .chain(Some(column.len()))
+                    .max()
+                    .unwrap()
+            })
+            .collect();
+        let line = |cells: Vec<&str>| {
+            let padded: Vec<String> = cells
+                .iter()
+                .zip(widths.iter())
+                .map(|(cell, &width)| format!("{:<w$}", cell, w = width))
+                .collect();
+            format!("{}\n", padded.join("  ").trim_end())
+        };
+        let mut table = line(self.columns.clone());
+        for row in cells.iter() {
+            table.push_str(&line(row.iter().map(String::as_str).collect()));
+        }
+        table
+    }
+
+    fn render_fields(&self) -> String {
+        let width = self.columns.iter().map(|c| c.len()).max().unwrap_or(0);
+        let mut table = String::new();
+        for row in self.rows.iter() {
+            for (column, value) in self.columns.iter().zip(row.iter()) {
+                writeln!(table, "{:<w$}  {}", column, value.to_table(), w = width).unwrap();
+            }
+        }
+        table
+    }
+}
@@ -0,0 +1,144 @@
+//! Nuclear binding energies, from the tabulated isotope masses or from the
+//! semi-empirical mass formula.
+
+use crate::{Element, Isotope};
+use std::convert::TryFrom;
+
+/// Mass of a ¹H atom in u. Using atomic rather than nuclear masses, the
+/// masses of the electrons cancel out (up to their own binding energy).
+const HYDROGEN_ATOM_MASS: f64 = 1.007_825_032;
+/// In u.
+const NEUTRON_MASS: f64 = 1.008_664_916;
+/// Energy equivalent of 1 u in MeV.
+const ATOMIC_MASS_UNIT_ENERGY: f64 = 931.494_102;
+
+// Coefficients of the semi-empirical mass formula in MeV
+const VOLUME: f64 = 15.8;
+const SURFACE: f64 = 18.3;
+const COULOMB: f64 = 0.714;
+const ASYMMETRY: f64 = 23.2;
+const PAIRING: f64 = 12.0;
+/// Below this mass number the semi-empirical formula is too far off to be
+/// used in place of a measured mass.
+const SEMI_EMPIRICAL_MIN_MASS_NUMBER: usize = 20;
+
+impl Isotope {
+    /// Difference in u between the mass of the separate nucleons and the
+    /// mass of the nucleus. `None` if the mass of the isotope is unknown.
+    pub fn get_mass_defect(&self) -> Option<f64> {
+        let z = self.element.get_atomic_number() as f64;
+        let n = self.mass_number as f64 - z;
+        Some(z * HYDROGEN_ATOM_MASS + n * NEUTRON_MASS - self.mass?)
+    }
+
+    /// In MeV.
+    pub fn get_binding_energy(&self) -> Option<f64> {
+        Some(self.get_mass_defect()? * ATOMIC_MASS_UNIT_ENERGY)
+    }
+
+    /// In MeV.
+    pub fn get_binding_energy_per_nucleon(&self) -> Option<f64> {
+        Some(self.get_binding_energy()? / self.mass_number as f64)
+    }
+}
+
+/// Estimates the binding energy in MeV of a nucleus with `z` protons and
+/// `a` nucleons with the Weizsäcker formula
+///
+/// `B = aV·A - aS·A^(2/3) - aC·Z(Z-1)/A^(1/3) - aA·(A-2Z)²/A ± aP/A^(1/2)`
+///
+/// where the pairing term is positive for even-even nuclei, negative for
+/// odd-odd ones and zero otherwise. Accurate to about 1% for A > 30, but
+/// meaningless for the lightest nuclei.
+///
+/// Works for nuclides that are not tabulated or do not exist, `None` is
+/// returned only if `a` is zero or smaller than `z`.
+pub fn semi_empirical_binding_energy(z: usize, a: usize) -> Option<f64> {
+    if a == 0 || z > a {
+        return None;
+    }
+    let (zf, af) = (z as f64, a as f64);
+    let pairing = match (z % 2, (a - z) % 2) {
+        (0, 0) => PAIRING / af.sqrt(),
+        (1, 1) => -PAIRING / af.sqrt(),
+        _ => 0.0,
+    };
+    Some(
+        VOLUME * af
+            - SURFACE * af.powf(2.0 / 3.0)
+            - COULOMB * zf * (zf - 1.0) / af.cbrt()
+            - ASYMMETRY * (af - 2.0 * zf).powi(2) / af
+            + pairing,
+    )
+}
+
+/// The binding energy in MeV of a nucleus with `z` protons and `a` nucleons,
+/// from its measured mass if it is tabulated and from
+/// `semi_empirical_binding_energy` otherwise. `None` for the light nuclei
+/// (A < 20) without a tabulated mass, for which the estimate is meaningless.
+pub fn binding_energy(z: usize, a: usize) -> Option<f64> {
+    Element::from_atomic_number(z)
+        .and_then(|e| e.get_isotope(u16::try_from(a).ok()?))
+        .and_then(|i| i.get_binding_energy())
+        .or_else(|| {
+            if a < SEMI_EMPIRICAL_MIN_MASS_NUMBER {
+                None
+            } else {
+                semi_empirical_binding_energy(z, a)
+            }
+        })
+}
+
+/// In MeV, see `binding_energy`.
+pub fn binding_energy_per_nucleon(z: usize, a: usize) -> Option<f64> {
+    Some(binding_energy(z, a)? / a as f64)
+}
+
+/// The mass defect in u of a nucleus with `z` protons and `a` nucleons, see
+/// `binding_energy`.
+pub fn mass_defect(z: usize, a: usize) -> Option<f64> {
+    Some(binding_energy(z, a)? / ATOMIC_MASS_UNIT_ENERGY)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        binding_energy, binding_energy_per_nucleon, mass_defect, semi_empirical_binding_energy,
+    };
+
+    #[test]
+    fn iron_56() {
+        let measured = binding_energy(26, 56).unwrap();
+        assert!((measured - 492.26).abs() < 0.01, "{}", measured);
+        let estimate = semi_empirical_binding_energy(26, 56).unwrap();
+        assert!(
+            (estimate - measured).abs() / measured < 0.01,
+            "{}",
+            estimate
+        );
+    }
+
+    #[test]
+    fn light_nuclei() {
+        let tritium = binding_energy(1, 3).unwrap();
+        assert!((tritium - 8.48).abs() < 0.01, "{}", tritium);
+        assert_eq!(binding_energy(1, 4), None);
+        assert_eq!(binding_energy(5, 8), None);
+        assert!(binding_energy(8, 20).is_some());
+    }
+
+    #[test]
+    fn per_nucleon_and_mass_defect() {
+        let per_nucleon = binding_energy_per_nucleon(26, 56).unwrap();
+        assert!((per_nucleon - 8.790).abs() < 0.001, "{}", per_nucleon);
+        let defect = mass_defect(26, 56).unwrap();
+        assert!((defect - 0.528_46).abs() < 1e-4, "{}", defect);
+        assert!(mass_defect(120, 300).is_some());
+    }
+
+    #[test]
+    fn invalid_nuclei() {
+        assert_eq!(semi_empirical_binding_energy(0, 0), None);
+        assert_eq!(semi_empirical_binding_energy(3, 2), None);
+    }
+}
@@ -0,0 +1,60 @@
+use crate::Element;
+
+/// Electronegativity difference below which a bond is considered nonpolar covalent.
+const NONPOLAR_COVALENT_LIMIT: f32 = 0.4;
+/// Electronegativity difference above which a bond is considered ionic.
+const IONIC_LIMIT: f32 = 1.7;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum BondType {
+    NonPolarCovalent,
+    PolarCovalent,
+    Ionic,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct BondPolarity {
+    /// Absolute difference between the Pauling electronegativities of both elements.
+    pub electronegativity_difference: f32,
+    pub bond_type: BondType,
+    /// Percent ionic character, estimated with Pauling's formula
+    /// `100 * (1 - e^(-Δχ² / 4))`.
+    pub ionic_character: f32,
+}
+
+impl BondType {
+    pub fn from_electronegativity_difference(difference: f32) -> BondType {
+        let difference = difference.abs();
+        if difference < NONPOLAR_COVALENT_LIMIT {
+            BondType::NonPolarCovalent
+        } else if difference <= IONIC_LIMIT {
+            BondType::PolarCovalent
+        } else {
+            BondType::Ionic
+        }
+    }
+}
+
+impl BondPolarity {
+    pub fn from_electronegativity_difference(difference: f32) -> BondPolarity {
+        let difference = difference.abs();
+        BondPolarity {
+            electronegativity_difference: difference,
+            bond_type: BondType::from_electronegativity_difference(difference),
+            ionic_character: 100.0 * (1.0 - (-difference * difference / 4.0).exp()),
+        }
+    }
+}
+
+impl Element {
+    /// Classifies the bond between `self` and `other` from their Pauling
+    /// electronegativities.
+    ///
+    /// Returns `None` if either element has no known electronegativity
+    /// (e.g. most noble gases).
+    pub fn bond_polarity(&self, other: Element) -> Option<BondPolarity> {
+        let a = self.get_electronegativity_checked()?;
+        let b = other.get_electronegativity_checked()?;
+        Some(BondPolarity::from_electronegativity_difference(a - b))
+    }
+}
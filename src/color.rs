@@ -0,0 +1,119 @@
+//! Colours used to draw atoms and periodic tables.
+
+use crate::{Element, GroupBlock, CPK, JMOL_COLORS, PYMOL_COLORS, RASMOL_COLORS};
+use std::fmt;
+
+/// Fill of the table cells without a colour or a value.
+pub(crate) const MISSING: Color = Color::new(0xE0, 0xE0, 0xE0);
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Color {
+    pub const fn new(r: u8, g: u8, b: u8) -> Color {
+        Color { r, g, b }
+    }
+
+    /// e.g. "#FF0D0D".
+    pub fn to_hex(&self) -> String {
+        format!("#{:02X}{:02X}{:02X}", self.r, self.g, self.b)
+    }
+
+    /// e.g. "rgb(255, 13, 13)".
+    pub fn to_css(&self) -> String {
+        format!("rgb({}, {}, {})", self.r, self.g, self.b)
+    }
+}
+
+impl From<[u8; 3]> for Color {
+    fn from(rgb: [u8; 3]) -> Color {
+        Color::new(rgb[0], rgb[1], rgb[2])
+    }
+}
+
+impl From<Color> for [u8; 3] {
+    fn from(color: Color) -> [u8; 3] {
+        [color.r, color.g, color.b]
+    }
+}
+
+/// Formats as `to_hex`.
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:02X}{:02X}{:02X}", self.r, self.g, self.b)
+    }
+}
+
+/// Black or white, whichever reads better on `background`.
+pub(crate) fn text_color(background: Color) -> Color {
+    let luma =
+        0.299 * background.r as f32 + 0.587 * background.g as f32 + 0.114 * background.b as f32;
+    if luma < 128.0 {
+        Color::new(0xFF, 0xFF, 0xFF)
+    } else {
+        Color::new(0, 0, 0)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum ColorScheme {
+    /// The colours of PubChem, the same as `get_cpk`.
+    Cpk,
+    /// The colours of Jmol, defined up to meitnerium.
+    Jmol,
+    /// The original CPK colours of RasMol, which only cover the elements
+    /// common in biomolecules.
+    RasMol,
+    /// The colours of PyMOL, which are those of Jmol except for hydrogen,
+    /// carbon, nitrogen, oxygen and sulfur.
+    PyMol,
+    /// A colour for each `GroupBlock`, as in most printed periodic tables.
+    Category,
+}
+
+impl ColorScheme {
+    pub const ALL: [ColorScheme; 5] = [
+        ColorScheme::Cpk,
+        ColorScheme::Jmol,
+        ColorScheme::RasMol,
+        ColorScheme::PyMol,
+        ColorScheme::Category,
+    ];
+}
+
+impl GroupBlock {
+    /// The colour of the group in the `Category` scheme.
+    pub fn get_color(&self) -> Color {
+        match self {
+            GroupBlock::AlkaliMetal => Color::new(0xFF, 0x66, 0x66),
+            GroupBlock::AlkalineEarthMetal => Color::new(0xFF, 0xDE, 0xAD),
+            GroupBlock::Lanthanide => Color::new(0xFF, 0xBF, 0xFF),
+            GroupBlock::Actinide => Color::new(0xFF, 0x99, 0xCC),
+            GroupBlock::TransitionMetal => Color::new(0xFF, 0xC0, 0xC0),
+            GroupBlock::PostTransitionMetal => Color::new(0xCC, 0xCC, 0xCC),
+            GroupBlock::Metalloid => Color::new(0xCC, 0xCC, 0x99),
+            GroupBlock::NonMetal => Color::new(0xA0, 0xFF, 0xA0),
+            GroupBlock::Halogen => Color::new(0xFF, 0xFF, 0x99),
+            GroupBlock::NobleGas => Color::new(0xC0, 0xFF, 0xFF),
+        }
+    }
+}
+
+impl Element {
+    /// Returns `None` for the elements the scheme has no colour for, rather
+    /// than the black `get_cpk` falls back to.
+    pub fn get_color(&self, scheme: ColorScheme) -> Option<Color> {
+        let rgb = match scheme {
+            ColorScheme::Cpk => Some(CPK[*self as usize]).filter(|&rgb| rgb != [0, 0, 0]),
+            ColorScheme::Jmol => JMOL_COLORS[*self as usize],
+            ColorScheme::RasMol => RASMOL_COLORS[*self as usize],
+            ColorScheme::PyMol => PYMOL_COLORS[*self as usize],
+            ColorScheme::Category => return Some(self.get_group().get_color()),
+        };
+        rgb.map(Color::from)
+    }
+}
@@ -0,0 +1,109 @@
+use std::fmt;
+
+use crate::ElectronicConfiguration;
+
+/// The kind of a subshell, given by its azimuthal quantum number.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum SubshellKind {
+    S,
+    P,
+    D,
+    F,
+    /// Only occupied in hypothetical elements beyond Z = 120.
+    G,
+}
+
+impl SubshellKind {
+    /// The azimuthal quantum number `l`.
+    #[inline(always)]
+    pub fn azimuthal_quantum_number(&self) -> u8 {
+        *self as u8
+    }
+
+    /// The maximum number of electrons the subshell can hold.
+    #[inline(always)]
+    pub fn capacity(&self) -> u8 {
+        4 * self.azimuthal_quantum_number() + 2
+    }
+
+    pub fn letter(&self) -> char {
+        match self {
+            SubshellKind::S => 's',
+            SubshellKind::P => 'p',
+            SubshellKind::D => 'd',
+            SubshellKind::F => 'f',
+            SubshellKind::G => 'g',
+        }
+    }
+}
+
+/// A subshell such as `1s` or `3d`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Subshell {
+    /// The principal quantum number.
+    pub n: u8,
+    pub kind: SubshellKind,
+}
+
+impl Subshell {
+    #[inline(always)]
+    pub fn new(n: u8, kind: SubshellKind) -> Subshell {
+        Subshell { n, kind }
+    }
+}
+
+impl fmt::Display for Subshell {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", self.n, self.kind.letter())
+    }
+}
+
+impl ElectronicConfiguration {
+    /// Number of electrons in the subshell, zero if it is empty or can't be
+    /// represented.
+    pub fn get(&self, subshell: Subshell) -> u8 {
+        let n = subshell.n as usize;
+        let slot = match subshell.kind {
+            SubshellKind::S => n.checked_sub(1).and_then(|i| self.s.get(i)),
+            SubshellKind::P => n.checked_sub(2).and_then(|i| self.p.get(i)),
+            SubshellKind::D => n.checked_sub(3).and_then(|i| self.d.get(i)),
+            SubshellKind::F => n.checked_sub(4).and_then(|i| self.f.get(i)),
+            SubshellKind::G => None,
+        };
+        slot.copied().unwrap_or(0)
+    }
+
+    /// The occupied subshells with their number of electrons, sorted by
+    /// principal and then azimuthal quantum number.
+    pub fn subshells(&self) -> Vec<(Subshell, u8)> {
+        let mut subshells = Vec::new();
+        for n in 1..=7 {
+            for &kind in [
+                SubshellKind::S,
+                SubshellKind::P,
+                SubshellKind::D,
+                SubshellKind::F,
+            ]
+            .iter()
+            {
+                let subshell = Subshell::new(n, kind);
+                let electrons = self.get(subshell);
+                if electrons > 0 {
+                    subshells.push((subshell, electrons));
+                }
+            }
+        }
+        subshells
+    }
+
+    /// The total number of electrons.
+    pub fn electron_count(&self) -> u32 {
+        self.s
+            .iter()
+            .chain(self.p.iter())
+            .chain(self.d.iter())
+            .chain(self.f.iter())
+            .map(|&e| e as u32)
+            .sum()
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{Element, CRYSTAL_STRUCTURES, CRYSTAL_STRUCTURES_DATA};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum CrystalSystem {
+    Triclinic,
+    Monoclinic,
+    Orthorhombic,
+    Tetragonal,
+    Trigonal,
+    Hexagonal,
+    Cubic,
+}
+
+/// Lattice centering, which together with the crystal system gives the
+/// Bravais lattice.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Centering {
+    Primitive,
+    BodyCentered,
+    FaceCentered,
+    BaseCentered,
+    Rhombohedral,
+}
+
+/// Conventional unit cell parameters. Rhombohedral lattices use the
+/// hexagonal setting.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct LatticeConstants {
+    /// In picometers.
+    pub a: f32,
+    /// In picometers.
+    pub b: f32,
+    /// In picometers.
+    pub c: f32,
+    /// In degrees.
+    pub alpha: f32,
+    /// In degrees.
+    pub beta: f32,
+    /// In degrees.
+    pub gamma: f32,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct CrystalStructure {
+    /// Name of the allotrope or phase, e.g. "diamond" or "alpha".
+    pub allotrope: &'static str,
+    pub system: CrystalSystem,
+    pub centering: Centering,
+    /// Number in the International Tables for Crystallography.
+    pub space_group: u8,
+    /// Hermann-Mauguin symbol, with `_` before screw axis subscripts.
+    pub space_group_symbol: &'static str,
+    pub lattice: LatticeConstants,
+}
+
+impl LatticeConstants {
+    /// Volume of the unit cell in cubic picometers.
+    pub fn volume(&self) -> f32 {
+        let (ca, cb, cg) = (
+            self.alpha.to_radians().cos(),
+            self.beta.to_radians().cos(),
+            self.gamma.to_radians().cos(),
+        );
+        self.a * self.b * self.c * (1.0 - ca * ca - cb * cb - cg * cg + 2.0 * ca * cb * cg).sqrt()
+    }
+}
+
+impl Element {
+    /// The crystal structure of the most stable form of the solid element at
+    /// standard conditions (or at low temperature for elements that aren't
+    /// solid), if known.
+    pub fn get_crystal_structure(&self) -> Option<&'static CrystalStructure> {
+        self.get_allotropes().first()
+    }
+
+    /// All tabulated solid forms of the element, the most stable one first.
+    pub fn get_allotropes(&self) -> &'static [CrystalStructure] {
+        let (start, len) = CRYSTAL_STRUCTURES[*self as usize];
+        &CRYSTAL_STRUCTURES_DATA[start as usize..start as usize + len as usize]
+    }
+}
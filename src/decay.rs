@@ -0,0 +1,339 @@
+//! Radioactive decay chains and the Bateman equations.
+//!
+//! Only the decays needed to follow the four natural decay series and a few
+//! other common radionuclides are tabulated. Branches below 10⁻⁴ are
+//! neglected, and ²³⁴Pa stands for the metastable ²³⁴ᵐPa that the uranium
+//! series goes through.
+
+use crate::{Element, Isotope, DECAYS_DATA, ISOTOPES_DATA};
+use std::f64::consts::LN_2;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum DecayMode {
+    Alpha,
+    BetaMinus,
+    BetaPlus,
+    ElectronCapture,
+}
+
+impl DecayMode {
+    /// Change of the atomic number and of the mass number of the nucleus.
+    pub fn nucleus_change(&self) -> (i8, i8) {
+        match self {
+            DecayMode::Alpha => (-2, -4),
+            DecayMode::BetaMinus => (1, 0),
+            DecayMode::BetaPlus | DecayMode::ElectronCapture => (-1, 0),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Decay {
+    pub mode: DecayMode,
+    /// Fraction of the decays of the parent that follow this mode.
+    pub branching_ratio: f64,
+    pub daughter: &'static Isotope,
+}
+
+impl Isotope {
+    /// λ = ln 2 / t½ in s⁻¹, zero for stable isotopes.
+    pub fn get_decay_constant(&self) -> f64 {
+        self.half_life.map_or(0.0, |t| LN_2 / t)
+    }
+
+    /// Empty for stable isotopes, and for radioactive ones whose daughters
+    /// are not tabulated.
+    pub fn get_decays(&self) -> Vec<Decay> {
+        let (start, len) = self.decays;
+        DECAYS_DATA[start as usize..start as usize + len as usize]
+            .iter()
+            .map(|&(mode, branching_ratio, daughter)| Decay {
+                mode,
+                branching_ratio,
+                daughter: &ISOTOPES_DATA[daughter as usize],
+            })
+            .collect()
+    }
+
+    /// The isotope and all of its descendants.
+    pub fn get_decay_chain(&'static self) -> DecayChain {
+        DecayChain::from_parent(self)
+    }
+}
+
+/// The four decay series of the heavy elements, named after the nuclide
+/// each one is traditionally named after. Alpha decay changes the mass
+/// number by four and beta decay does not change it, so the series of a
+/// nuclide is given by its mass number modulo 4.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum DecaySeries {
+    /// 4n, from ²³²Th.
+    Thorium,
+    /// 4n + 1, from ²³⁷Np. Extinct in nature.
+    Neptunium,
+    /// 4n + 2, from ²³⁸U.
+    Uranium,
+    /// 4n + 3, from ²³⁵U.
+    Actinium,
+}
+
+impl DecaySeries {
+    pub const ALL: [DecaySeries; 4] = [
+        DecaySeries::Thorium,
+        DecaySeries::Neptunium,
+        DecaySeries::Uranium,
+        DecaySeries::Actinium,
+    ];
+
+    pub fn from_mass_number(mass_number: u16) -> DecaySeries {
+        DecaySeries::ALL[mass_number as usize % 4]
+    }
+
+    /// The longest-lived nuclide of the series.
+    pub fn get_parent(&self) -> &'static Isotope {
+        let (element, mass_number) = match self {
+            DecaySeries::Thorium => (Element::Thorium, 232),
+            DecaySeries::Neptunium => (Element::Neptunium, 237),
+            DecaySeries::Uranium => (Element::Uranium, 238),
+            DecaySeries::Actinium => (Element::Uranium, 235),
+        };
+        element.get_isotope(mass_number).unwrap()
+    }
+
+    pub fn get_chain(&self) -> DecayChain {
+        self.get_parent().get_decay_chain()
+    }
+}
+
+/// A decay of a chain, between two of its nuclides.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct DecayLink {
+    /// Index of the parent in `DecayChain::nuclides`.
+    pub parent: usize,
+    /// Index of the daughter in `DecayChain::nuclides`.
+    pub daughter: usize,
+    pub mode: DecayMode,
+    pub branching_ratio: f64,
+}
+
+/// A directed acyclic graph of nuclides and the decays between them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecayChain {
+    /// Sorted so that parents always come before their daughters.
+    pub nuclides: Vec<&'static Isotope>,
+    pub links: Vec<DecayLink>,
+}
+
+fn same_nuclide(a: &Isotope, b: &Isotope) -> bool {
+    a.element == b.element && a.mass_number == b.mass_number
+}
+
+/// `e^(-a·t) - e^(-b·t)`, without losing precision when `a` and `b` are
+/// close or very different.
+fn exp_difference(a: f64, b: f64, t: f64) -> f64 {
+    if a < b {
+        -(-a * t).exp() * (-(b - a) * t).exp_m1()
+    } else {
+        (-b * t).exp() * (-(a - b) * t).exp_m1()
+    }
+}
+
+impl DecayChain {
+    pub fn from_parent(parent: &'static Isotope) -> DecayChain {
+        // Collect the descendants breadth first
+        let mut found = vec![parent];
+        let mut links = Vec::new();
+        let mut i = 0;
+        while i < found.len() {
+            for decay in found[i].get_decays() {
+                let daughter = match found.iter().position(|n| same_nuclide(n, decay.daughter)) {
+                    Some(daughter) => daughter,
+                    None => {
+                        found.push(decay.daughter);
+                        found.len() - 1
+                    }
+                };
+                links.push((i, daughter, decay));
+            }
+            i += 1;
+        }
+        // Then sort them topologically, keeping the breadth first order when
+        // there is a choice
+        let mut parents = vec![0; found.len()];
+        for &(_, daughter, _) in links.iter() {
+            parents[daughter] += 1;
+        }
+        let mut ready: Vec<usize> = (0..found.len()).filter(|&i| parents[i] == 0).collect();
+        let mut order = Vec::with_capacity(found.len());
+        let mut position = vec![0; found.len()];
+        while !ready.is_empty() {
+            ready.sort_unstable();
+            let i = ready.remove(0);
+            position[i] = order.len();
+            order.push(i);
+            for &(_, daughter, _) in links.iter().filter(|(parent, _, _)| *parent == i) {
+                parents[daughter] -= 1;
+                if parents[daughter] == 0 {
+                    ready.push(daughter);
+                }
+            }
+        }
+        DecayChain {
+            nuclides: order.iter().map(|&i| found[i]).collect(),
+            links: links
+                .into_iter()
+                .map(|(parent, daughter, decay)| DecayLink {
+                    parent: position[parent],
+                    daughter: position[daughter],
+                    mode: decay.mode,
+                    branching_ratio: decay.branching_ratio,
+                })
+                .collect(),
+        }
+    }
+
+    /// Index of a nuclide in `nuclides`.
+    pub fn position(&self, element: Element, mass_number: u16) -> Option<usize> {
+        self.nuclides
+            .iter()
+            .position(|n| n.element == element && n.mass_number == mass_number)
+    }
+
+    /// Solves the Bateman equations: given the amounts of each nuclide at
+    /// time zero, in the order of `nuclides`, returns their amounts after
+    /// `time` seconds. Any unit of amount (atoms, moles, grams of parent
+    /// equivalent...) works as long as it is the same for all nuclides.
+    ///
+    /// Uses the analytical solution, which assumes that no two nuclides
+    /// linked by a decay path have the same decay constant.
+    ///
+    /// # Panics
+    ///
+    /// If `initial` and `nuclides` have different lengths.
+    pub fn amounts_at(&self, initial: &[f64], time: f64) -> Vec<f64> {
+        assert_eq!(initial.len(), self.nuclides.len());
+        let n = self.nuclides.len();
+        let lambda: Vec<f64> = self
+            .nuclides
+            .iter()
+            .map(|n| n.get_decay_constant())
+            .collect();
+        // The amount of nuclide i is the sum of c[i][j]·e^(-λj·t) over the
+        // nuclides j up to i
+        let mut c = vec![vec![0.0; n]; n];
+        for i in 0..n {
+            for j in 0..i {
+                let feed: f64 = self
+                    .links
+                    .iter()
+                    .filter(|link| link.daughter == i)
+                    .map(|link| link.branching_ratio * lambda[link.parent] * c[link.parent][j])
+                    .sum();
+                if feed != 0.0 {
+                    c[i][j] = feed / (lambda[i] - lambda[j]);
+                }
+            }
+            c[i][i] = initial[i] - c[i][..i].iter().sum::<f64>();
+        }
+        (0..n)
+            .map(|i| {
+                let amount = initial[i] * (-lambda[i] * time).exp()
+                    + (0..i)
+                        .map(|j| c[i][j] * exp_difference(lambda[j], lambda[i], time))
+                        .sum::<f64>();
+                amount.max(0.0)
+            })
+            .collect()
+    }
+
+    /// Activities in becquerels of amounts given in atoms, in the order of
+    /// `nuclides`.
+    pub fn activities(&self, amounts: &[f64]) -> Vec<f64> {
+        self.nuclides
+            .iter()
+            .zip(amounts.iter())
+            .map(|(n, amount)| n.get_decay_constant() * amount)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Element;
+
+    const YEAR: f64 = 365.25 * 24.0 * 3600.0;
+
+    fn relative_difference(a: f64, b: f64) -> f64 {
+        (a - b).abs() / b.abs()
+    }
+
+    #[test]
+    fn uranium_series_reaches_secular_equilibrium() {
+        let chain = Element::Uranium.get_isotope(238).unwrap().get_decay_chain();
+        let mut initial = vec![0.0; chain.nuclides.len()];
+        initial[0] = 1e20;
+        // Ten times the half-life of ²³⁴U, the longest-lived daughter
+        let amounts = chain.amounts_at(&initial, 2.5e6 * YEAR);
+        let activities = chain.activities(&amounts);
+        for (i, nuclide) in chain.nuclides.iter().enumerate().skip(1) {
+            if nuclide.half_life.is_none() {
+                continue;
+            }
+            let fed: f64 = chain
+                .links
+                .iter()
+                .filter(|link| link.daughter == i)
+                .map(|link| link.branching_ratio * activities[link.parent])
+                .sum();
+            assert!(
+                relative_difference(activities[i], fed) < 1e-3,
+                "{}-{}: {} Bq instead of {} Bq",
+                nuclide.element.get_symbol(),
+                nuclide.mass_number,
+                activities[i],
+                fed
+            );
+        }
+    }
+
+    #[test]
+    fn uranium_series_conserves_the_amount() {
+        let chain = Element::Uranium.get_isotope(238).unwrap().get_decay_chain();
+        let mut initial = vec![0.0; chain.nuclides.len()];
+        initial[0] = 1.0;
+        for &time in &[0.0, 1.0, 1e3 * YEAR, 1e6 * YEAR, 1e10 * YEAR] {
+            let total: f64 = chain.amounts_at(&initial, time).iter().sum();
+            assert!(
+                relative_difference(total, 1.0) < 1e-9,
+                "{} at {} s",
+                total,
+                time
+            );
+        }
+        let lead = chain.position(Element::Lead, 206).unwrap();
+        let amounts = chain.amounts_at(&initial, 4.468e9 * YEAR);
+        assert!(relative_difference(amounts[0], 0.5) < 1e-3);
+        assert!(relative_difference(amounts[lead], 0.5) < 1e-3);
+    }
+
+    #[test]
+    fn two_member_chain_matches_the_closed_form() {
+        // ¹⁴⁷Pm → ¹⁴⁷Sm → ¹⁴³Nd
+        let chain = Element::Promethium
+            .get_isotope(147)
+            .unwrap()
+            .get_decay_chain();
+        assert_eq!(chain.nuclides.len(), 3);
+        let samarium = chain.position(Element::Samarium, 147).unwrap();
+        let lambda1 = chain.nuclides[0].get_decay_constant();
+        let lambda2 = chain.nuclides[samarium].get_decay_constant();
+        for &time in &[1.0 * YEAR, 10.0 * YEAR, 100.0 * YEAR, 1e11 * YEAR] {
+            let amounts = chain.amounts_at(&[1.0, 0.0, 0.0], time);
+            let parent = (-lambda1 * time).exp();
+            let daughter =
+                lambda1 / (lambda2 - lambda1) * ((-lambda1 * time).exp() - (-lambda2 * time).exp());
+            assert!((amounts[0] - parent).abs() < 1e-12);
+            assert!(relative_difference(amounts[samarium], daughter) < 1e-9);
+        }
+    }
+}
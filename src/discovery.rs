@@ -0,0 +1,40 @@
+use crate::{Element, DISCOVERIES};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum DiscoveryYear {
+    /// Known since antiquity, no discoverer is recorded.
+    Ancient,
+    Year(u16),
+}
+
+/// The story of how an element was found and named.
+///
+/// The years follow the historical accounts and, for the transfermium
+/// elements, the reports of the IUPAC/IUPAP Joint Working Party that credited
+/// their discovery. They can differ from `Element::get_year_discovered`, which
+/// comes from PubChem and, for example, counts aluminium as ancient.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Discovery {
+    /// When the element was first identified as such.
+    pub identified: DiscoveryYear,
+    /// When the element was first isolated in elemental form, if that
+    /// happened in a later year than its identification.
+    pub isolated: Option<u16>,
+    /// Empty for ancient elements.
+    pub discoverers: &'static [&'static str],
+    /// Countries where the discovery was made.
+    pub countries: &'static [&'static str],
+    /// Laboratories or institutions where the discovery was made, such as
+    /// GSI or RIKEN. Only given for the elements found in the laboratory
+    /// since the 20th century.
+    pub laboratories: &'static [&'static str],
+    /// Origin of the element's name.
+    pub etymology: &'static str,
+}
+
+impl Element {
+    #[inline(always)]
+    pub fn get_discovery(&self) -> Discovery {
+        DISCOVERIES[*self as usize]
+    }
+}
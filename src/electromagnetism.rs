@@ -0,0 +1,54 @@
+//! Electrical and magnetic properties of the elements in their standard
+//! state at room temperature.
+
+use crate::{
+    known, Element, ELECTRICAL_RESISTIVITIES, MAGNETIC_ORDERINGS, MOLAR_MAGNETIC_SUSCEPTIBILITIES,
+    SUPERCONDUCTING_TEMPERATURES,
+};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum MagneticOrdering {
+    Diamagnetic,
+    Paramagnetic,
+    Ferromagnetic,
+    Antiferromagnetic,
+}
+
+impl Element {
+    /// At 20 °C in Ω·m. Only known for metals and some metalloids.
+    #[inline(always)]
+    pub fn get_electrical_resistivity(&self) -> Option<f32> {
+        known(ELECTRICAL_RESISTIVITIES[*self as usize])
+    }
+
+    /// At 20 °C in S/m, the reciprocal of the resistivity.
+    #[inline(always)]
+    pub fn get_electrical_conductivity(&self) -> Option<f32> {
+        self.get_electrical_resistivity()
+            .map(|resistivity| 1.0 / resistivity)
+    }
+
+    #[inline(always)]
+    pub fn get_magnetic_ordering(&self) -> Option<MagneticOrdering> {
+        MAGNETIC_ORDERINGS[*self as usize]
+    }
+
+    /// In cm³/mol (CGS units); negative for diamagnetic elements. Unknown for
+    /// ferromagnets, whose magnetization is not proportional to the field.
+    #[inline(always)]
+    pub fn get_molar_magnetic_susceptibility(&self) -> Option<f32> {
+        MOLAR_MAGNETIC_SUSCEPTIBILITIES[*self as usize]
+    }
+
+    /// Critical temperature at ambient pressure in K, or `None` if the element
+    /// is not a known superconductor.
+    #[inline(always)]
+    pub fn get_superconducting_temperature(&self) -> Option<f32> {
+        known(SUPERCONDUCTING_TEMPERATURES[*self as usize])
+    }
+
+    #[inline(always)]
+    pub fn is_superconductor(&self) -> bool {
+        self.get_superconducting_temperature().is_some()
+    }
+}
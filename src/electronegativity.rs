@@ -0,0 +1,74 @@
+use crate::{
+    known, Element, ALLEN_ELECTRONEGATIVITIES, ALLRED_ROCHOW_ELECTRONEGATIVITIES,
+    SANDERSON_ELECTRONEGATIVITIES,
+};
+
+/// Elements whose anion is not bound, so that their electron affinity of zero
+/// is known rather than missing.
+const UNBOUND_ANIONS: [Element; 12] = [
+    Element::Helium,
+    Element::Beryllium,
+    Element::Nitrogen,
+    Element::Neon,
+    Element::Magnesium,
+    Element::Argon,
+    Element::Zinc,
+    Element::Krypton,
+    Element::Cadmium,
+    Element::Xenon,
+    Element::Mercury,
+    Element::Radon,
+];
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum ElectronegativityScale {
+    Pauling,
+    Allen,
+    AllredRochow,
+    /// Derived from the first ionization energy and the electron affinity, and
+    /// rescaled to Pauling units with `0.187 * (IE + EA) + 0.17`.
+    Mulliken,
+    Sanderson,
+}
+
+impl Element {
+    /// Returns the electronegativity of the element in the given scale, or
+    /// `None` if it is not known for this element.
+    pub fn get_electronegativity_in(&self, scale: ElectronegativityScale) -> Option<f32> {
+        let id = *self as usize;
+        match scale {
+            ElectronegativityScale::Pauling => self.get_electronegativity_checked(),
+            ElectronegativityScale::Allen => known(ALLEN_ELECTRONEGATIVITIES[id]),
+            ElectronegativityScale::AllredRochow => known(ALLRED_ROCHOW_ELECTRONEGATIVITIES[id]),
+            ElectronegativityScale::Mulliken => {
+                // The electron affinity is zero both when it is missing and
+                // when the anion is not bound, and only the latter is usable
+                let ie = known(self.get_ionization_energy())?;
+                let ea = match known(self.get_electron_affinity()) {
+                    Some(ea) => ea,
+                    None if UNBOUND_ANIONS.contains(self) => 0.0,
+                    None => return None,
+                };
+                Some(0.187 * (ie + ea) + 0.17)
+            }
+            ElectronegativityScale::Sanderson => known(SANDERSON_ELECTRONEGATIVITIES[id]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ElectronegativityScale::Mulliken;
+    use crate::Element;
+
+    #[test]
+    fn mulliken() {
+        let fluorine = Element::Fluorine
+            .get_electronegativity_in(Mulliken)
+            .unwrap();
+        assert!((fluorine - 4.06).abs() < 0.01, "{}", fluorine);
+        assert!(Element::Neon.get_electronegativity_in(Mulliken).is_some());
+        assert_eq!(Element::Calcium.get_electronegativity_in(Mulliken), None);
+        assert_eq!(Element::Europium.get_electronegativity_in(Mulliken), None);
+    }
+}
@@ -0,0 +1,240 @@
+use std::fmt;
+
+use crate::{madelung_configuration, Element, Subshell};
+
+const ROOTS: [&str; 10] = [
+    "nil", "un", "bi", "tri", "quad", "pent", "hex", "sept", "oct", "enn",
+];
+
+/// Either an element of the periodic table or a hypothetical one beyond
+/// oganesson, identified by its IUPAC systematic name.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum ExtendedElement {
+    Known(Element),
+    Systematic(SystematicNumber),
+}
+
+/// Atomic number of an element beyond oganesson. It can only be built by
+/// `ExtendedElement::from_atomic_number`, so that the elements of the periodic
+/// table are always `ExtendedElement::Known`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SystematicNumber(usize);
+
+impl SystematicNumber {
+    /// Always above 118.
+    #[inline(always)]
+    pub fn get(&self) -> usize {
+        self.0
+    }
+}
+
+/// The IUPAC systematic name for the atomic number `z`, e.g. `Ununennium`
+/// for 119. Also defined for the named elements (`Ununoctium` for 118).
+pub fn systematic_name(z: usize) -> String {
+    let digits = z.to_string();
+    let mut name = String::new();
+    for d in digits.bytes() {
+        let root = ROOTS[(d - b'0') as usize];
+        // "enn" followed by "nil" drops one "n".
+        if name.ends_with("nn") && root == "nil" {
+            name.pop();
+        }
+        name.push_str(root);
+    }
+    // "bi" and "tri" followed by "ium" drop the "i".
+    if name.ends_with('i') {
+        name.pop();
+    }
+    name.push_str("ium");
+    let mut chars = name.chars();
+    let first = chars.next().unwrap().to_ascii_uppercase();
+    std::iter::once(first).chain(chars).collect()
+}
+
+/// The IUPAC systematic symbol for the atomic number `z`, e.g. `Uue` for 119.
+pub fn systematic_symbol(z: usize) -> String {
+    let mut symbol = String::new();
+    for (i, d) in z.to_string().bytes().enumerate() {
+        let letter = ROOTS[(d - b'0') as usize].as_bytes()[0] as char;
+        if i == 0 {
+            symbol.push(letter.to_ascii_uppercase());
+        } else {
+            symbol.push(letter);
+        }
+    }
+    symbol
+}
+
+fn parse_systematic_symbol(sym: &str) -> Option<usize> {
+    let mut z = 0usize;
+    for (i, c) in sym.chars().enumerate() {
+        if (i == 0) != c.is_ascii_uppercase() {
+            return None;
+        }
+        let c = c.to_ascii_lowercase();
+        let digit = ROOTS.iter().position(|r| r.starts_with(c))?;
+        z = z.checked_mul(10)?.checked_add(digit)?;
+    }
+    // Reject leading zeros and symbols too short to be systematic.
+    if sym.len() < 3 || systematic_symbol(z) != sym {
+        return None;
+    }
+    Some(z)
+}
+
+fn parse_systematic_name(name: &str) -> Option<usize> {
+    let name = name.to_lowercase();
+    let mut rest = name.strip_suffix("ium")?.to_owned();
+    if rest.ends_with('b') || rest.ends_with("tr") {
+        rest.push('i');
+    }
+    let rest = rest.replace("ennil", "ennnil");
+    let mut rest = &rest[..];
+    let mut z = 0usize;
+    while !rest.is_empty() {
+        let digit = ROOTS.iter().position(|r| rest.starts_with(r))?;
+        rest = &rest[ROOTS[digit].len()..];
+        z = z.checked_mul(10)?.checked_add(digit)?;
+    }
+    // Only accept the canonical spelling, which has at least three roots.
+    if z < 100 || systematic_name(z).to_lowercase() != name {
+        return None;
+    }
+    Some(z)
+}
+
+impl ExtendedElement {
+    pub fn from_atomic_number(z: usize) -> Option<ExtendedElement> {
+        match z {
+            0 => None,
+            1..=118 => Element::from_atomic_number(z).map(ExtendedElement::Known),
+            _ => Some(ExtendedElement::Systematic(SystematicNumber(z))),
+        }
+    }
+
+    /// Accepts the symbols of the periodic table as well as IUPAC systematic
+    /// symbols such as `Uue`.
+    pub fn from_symbol(sym: &str) -> Option<ExtendedElement> {
+        match Element::from_symbol(sym) {
+            Some(e) => Some(ExtendedElement::Known(e)),
+            None => ExtendedElement::from_atomic_number(parse_systematic_symbol(sym)?),
+        }
+    }
+
+    /// Accepts the names of the periodic table as well as IUPAC systematic
+    /// names such as `Ununennium`, ignoring case.
+    pub fn from_name(name: &str) -> Option<ExtendedElement> {
+        match Element::from_name_case_insensitive(name) {
+            Some(e) => Some(ExtendedElement::Known(e)),
+            None => ExtendedElement::from_atomic_number(parse_systematic_name(name)?),
+        }
+    }
+
+    pub fn get_atomic_number(&self) -> usize {
+        match self {
+            ExtendedElement::Known(e) => e.get_atomic_number(),
+            ExtendedElement::Systematic(z) => z.0,
+        }
+    }
+
+    pub fn get_symbol(&self) -> String {
+        match self {
+            ExtendedElement::Known(e) => e.get_symbol().to_owned(),
+            ExtendedElement::Systematic(z) => systematic_symbol(z.0),
+        }
+    }
+
+    pub fn get_name(&self) -> String {
+        match self {
+            ExtendedElement::Known(e) => e.get_name().to_owned(),
+            ExtendedElement::Systematic(z) => systematic_name(z.0),
+        }
+    }
+
+    pub fn as_element(&self) -> Option<Element> {
+        match self {
+            ExtendedElement::Known(e) => Some(*e),
+            ExtendedElement::Systematic(_) => None,
+        }
+    }
+
+    /// The ground state configuration subshells for known elements, or the
+    /// Madelung rule prediction for hypothetical ones.
+    pub fn get_configuration_subshells(&self) -> Option<Vec<(Subshell, u8)>> {
+        match self {
+            ExtendedElement::Known(e) => Some(e.get_electronic_configuration().subshells()),
+            ExtendedElement::Systematic(z) => madelung_configuration(z.0),
+        }
+    }
+}
+
+impl From<Element> for ExtendedElement {
+    fn from(e: Element) -> ExtendedElement {
+        ExtendedElement::Known(e)
+    }
+}
+
+impl fmt::Display for ExtendedElement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.get_symbol())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{systematic_name, systematic_symbol, ExtendedElement};
+    use crate::Element;
+
+    #[test]
+    fn round_trips() {
+        for &(z, name, symbol) in &[
+            (119, "Ununennium", "Uue"),
+            (120, "Unbinilium", "Ubn"),
+            (190, "Unennilium", "Uen"),
+            (200, "Binilnilium", "Bnn"),
+            (999, "Ennennennium", "Eee"),
+            (1000, "Unnilnilnilium", "Unnn"),
+        ] {
+            assert_eq!(systematic_name(z), name);
+            assert_eq!(systematic_symbol(z), symbol);
+            let element = ExtendedElement::from_atomic_number(z).unwrap();
+            assert_eq!(element.get_atomic_number(), z);
+            assert_eq!(ExtendedElement::from_name(name), Some(element));
+            assert_eq!(ExtendedElement::from_symbol(symbol), Some(element));
+        }
+    }
+
+    #[test]
+    fn known_elements() {
+        assert_eq!(
+            ExtendedElement::from_atomic_number(26),
+            Some(ExtendedElement::Known(Element::Iron))
+        );
+        assert_eq!(ExtendedElement::from_atomic_number(0), None);
+        let oganesson = Some(ExtendedElement::Known(Element::Oganesson));
+        assert_eq!(ExtendedElement::from_name("Ununoctium"), oganesson);
+        assert_eq!(ExtendedElement::from_symbol("Uuo"), oganesson);
+        assert_eq!(ExtendedElement::from_name("Oganesson"), oganesson);
+        assert_eq!(systematic_name(112), "Ununbium");
+        assert_eq!(systematic_name(113), "Ununtrium");
+    }
+
+    #[test]
+    fn non_canonical_spellings() {
+        for name in &[
+            "Unennnilium",
+            "Ununbiium",
+            "Ununtriium",
+            "Ununb",
+            "Ununennium ",
+            "Nilununium",
+            "Unun",
+            "Ennium",
+        ] {
+            assert_eq!(ExtendedElement::from_name(name), None, "{}", name);
+        }
+        for symbol in &["UUe", "uue", "Nuu", "Ue", "Uux"] {
+            assert_eq!(ExtendedElement::from_symbol(symbol), None, "{}", symbol);
+        }
+    }
+}
@@ -0,0 +1,130 @@
+//! Hazards of the elemental substance in its standard state (e.g. white
+//! phosphorus, bulk metals unless they are only sold as powder), following
+//! the harmonised EU classification where there is one and the safety data
+//! sheets of suppliers otherwise.
+
+use crate::{Element, HAZARDS};
+
+/// GHS hazard pictograms.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum GhsPictogram {
+    Explosive,
+    Flammable,
+    Oxidizing,
+    CompressedGas,
+    Corrosive,
+    /// Skull and crossbones.
+    AcuteToxicity,
+    /// Exclamation mark.
+    Harmful,
+    HealthHazard,
+    EnvironmentalHazard,
+}
+
+impl GhsPictogram {
+    /// The GHS code, from "GHS01" to "GHS09".
+    pub fn code(&self) -> &'static str {
+        match self {
+            GhsPictogram::Explosive => "GHS01",
+            GhsPictogram::Flammable => "GHS02",
+            GhsPictogram::Oxidizing => "GHS03",
+            GhsPictogram::CompressedGas => "GHS04",
+            GhsPictogram::Corrosive => "GHS05",
+            GhsPictogram::AcuteToxicity => "GHS06",
+            GhsPictogram::Harmful => "GHS07",
+            GhsPictogram::HealthHazard => "GHS08",
+            GhsPictogram::EnvironmentalHazard => "GHS09",
+        }
+    }
+}
+
+/// The white (bottom) quadrant of the NFPA 704 diamond.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum NfpaSpecial {
+    /// W, reacts with water in a dangerous way.
+    WaterReactive,
+    /// OX.
+    Oxidizer,
+    /// SA, gases that only displace oxygen.
+    SimpleAsphyxiant,
+}
+
+/// The NFPA 704 "fire diamond". Ratings go from 0 (no hazard) to 4 (severe
+/// hazard).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Nfpa704 {
+    pub health: u8,
+    pub flammability: u8,
+    pub instability: u8,
+    pub special: Option<NfpaSpecial>,
+}
+
+/// Where a GHS classification comes from.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum HazardSource {
+    /// The harmonised classification of Annex VI of the EU CLP regulation.
+    Harmonised,
+    /// The safety data sheets of suppliers, for the substances without a
+    /// harmonised classification.
+    Supplier,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Hazards {
+    /// Empty if the substance is not classified as hazardous.
+    pub pictograms: &'static [GhsPictogram],
+    /// GHS hazard statement codes, e.g. "H260".
+    pub hazard_statements: &'static [&'static str],
+    pub nfpa_704: Option<Nfpa704>,
+    pub source: HazardSource,
+}
+
+impl Hazards {
+    /// Whether the substance is classified as hazardous at all.
+    pub fn is_classified(&self) -> bool {
+        !self.hazard_statements.is_empty()
+    }
+
+    fn has_statement(&self, codes: &[&str]) -> bool {
+        self.hazard_statements
+            .iter()
+            .any(|h| codes.iter().any(|code| h.starts_with(code)))
+    }
+
+    /// Catches fire spontaneously in air (H250).
+    pub fn is_pyrophoric(&self) -> bool {
+        self.has_statement(&["H250"])
+    }
+
+    /// Releases flammable gases in contact with water (H260, H261).
+    pub fn is_water_reactive(&self) -> bool {
+        self.has_statement(&["H260", "H261"])
+    }
+
+    /// Fatal or toxic by ingestion, skin contact or inhalation (H300, H301,
+    /// H310, H311, H330 and H331).
+    pub fn is_toxic(&self) -> bool {
+        self.has_statement(&["H300", "H301", "H310", "H311", "H330", "H331"])
+    }
+
+    /// May cause or intensify fire (H270 to H272).
+    pub fn is_oxidizer(&self) -> bool {
+        self.has_statement(&["H270", "H271", "H272"])
+    }
+
+    /// May cause cancer (H350).
+    pub fn is_carcinogenic(&self) -> bool {
+        self.has_statement(&["H350"])
+    }
+}
+
+impl Element {
+    /// Returns `None` for the elements without hazard data, which are the
+    /// radioactive ones (see `is_radioactive`) other than bismuth and uranium.
+    /// Elements that are known not to be hazardous have a `Hazards` that is
+    /// not classified (see `Hazards::is_classified`).
+    #[inline(always)]
+    pub fn get_hazards(&self) -> Option<Hazards> {
+        HAZARDS[*self as usize]
+    }
+}
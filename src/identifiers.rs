@@ -0,0 +1,83 @@
+//! Registry identifiers of the elemental substance, to join with external
+//! datasets. The InChI, InChIKey and PubChem CID refer to the molecule for
+//! the diatomic elements (H₂, N₂, O₂, F₂, Cl₂, Br₂ and I₂) and to the atom for
+//! the others, while ChEBI IDs always refer to the atom and Wikidata IDs to
+//! the chemical element.
+
+use crate::{
+    Element, CAS_NUMBERS, CAS_NUMBERS_SORTED, CHEBI_IDS, CHEBI_IDS_SORTED, INCHIS, INCHIS_SORTED,
+    INCHI_KEYS, INCHI_KEYS_SORTED, PUBCHEM_CIDS, PUBCHEM_CIDS_SORTED, WIKIDATA_IDS,
+    WIKIDATA_IDS_SORTED,
+};
+
+/// The generated tables use zero for unknown ids.
+#[inline(always)]
+fn known_id(id: u32) -> Option<u32> {
+    if id > 0 {
+        Some(id)
+    } else {
+        None
+    }
+}
+
+impl Element {
+    /// The CAS Registry Number, e.g. "7440-44-0" for carbon.
+    #[inline(always)]
+    pub fn get_cas_number(&self) -> Option<&'static str> {
+        CAS_NUMBERS[*self as usize]
+    }
+
+    #[inline(always)]
+    pub fn get_pubchem_cid(&self) -> Option<u32> {
+        known_id(PUBCHEM_CIDS[*self as usize])
+    }
+
+    /// The number of the ChEBI id, without the "CHEBI:" prefix.
+    #[inline(always)]
+    pub fn get_chebi_id(&self) -> Option<u32> {
+        known_id(CHEBI_IDS[*self as usize])
+    }
+
+    /// The standard InChI, e.g. "InChI=1S/Fe".
+    #[inline(always)]
+    pub fn get_inchi(&self) -> Option<&'static str> {
+        INCHIS[*self as usize]
+    }
+
+    /// The standard InChIKey, e.g. "XEEYBQQBJWHFJM-UHFFFAOYSA-N" for iron.
+    #[inline(always)]
+    pub fn get_inchi_key(&self) -> Option<&'static str> {
+        INCHI_KEYS[*self as usize]
+    }
+
+    /// The Wikidata item, e.g. "Q677" for iron.
+    #[inline(always)]
+    pub fn get_wikidata_id(&self) -> Option<&'static str> {
+        WIKIDATA_IDS[*self as usize]
+    }
+
+    pub fn from_cas(cas: &str) -> Option<Element> {
+        lookup!(CAS_NUMBERS_SORTED, cas)
+    }
+
+    pub fn from_pubchem_cid(cid: u32) -> Option<Element> {
+        lookup!(PUBCHEM_CIDS_SORTED, cid)
+    }
+
+    /// Accepts the number of the ChEBI id, without the "CHEBI:" prefix.
+    pub fn from_chebi_id(id: u32) -> Option<Element> {
+        lookup!(CHEBI_IDS_SORTED, id)
+    }
+
+    pub fn from_inchi(inchi: &str) -> Option<Element> {
+        lookup!(INCHIS_SORTED, inchi)
+    }
+
+    pub fn from_inchi_key(key: &str) -> Option<Element> {
+        lookup!(INCHI_KEYS_SORTED, key)
+    }
+
+    pub fn from_wikidata_id(id: &str) -> Option<Element> {
+        lookup!(WIKIDATA_IDS_SORTED, id)
+    }
+}
@@ -0,0 +1,127 @@
+use crate::{Element, ISOTOPES, ISOTOPES_DATA};
+
+/// Half-lives above this many seconds (10⁸ years) are long enough for a
+/// nuclide to have survived since the formation of the Solar System.
+const PRIMORDIAL_HALF_LIFE: f64 = 3.156e15;
+/// Plutonium is the heaviest element found in nature, as traces in uranium
+/// ores.
+const HEAVIEST_NATURAL_ATOMIC_NUMBER: usize = 94;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Isotope {
+    pub element: Element,
+    pub mass_number: u16,
+    /// In seconds, `None` if the isotope is stable.
+    pub half_life: Option<f64>,
+    /// Atomic mass (including the electrons) in unified atomic mass units.
+    pub mass: Option<f64>,
+    /// Natural abundance in atom percent, `None` for isotopes that only
+    /// occur as short-lived decay products or are synthetic.
+    pub abundance: Option<f64>,
+    /// Range of the decays of the isotope in `DECAYS_DATA`.
+    pub(crate) decays: (u16, u8),
+}
+
+/// How an element occurs on Earth.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum NaturalOccurrence {
+    /// Has stable isotopes or isotopes old enough to predate the Earth.
+    Primordial,
+    /// Only found as the short-lived product of the decay of heavier
+    /// elements (or of their spontaneous fission).
+    FromDecay,
+    /// Only made artificially.
+    Synthetic,
+}
+
+impl Isotope {
+    #[inline(always)]
+    pub fn is_stable(&self) -> bool {
+        self.half_life.is_none()
+    }
+
+    /// In years, `None` if the isotope is stable.
+    pub fn get_half_life_in_years(&self) -> Option<f64> {
+        self.half_life.map(|t| t / 31_556_926.0)
+    }
+
+    fn is_primordial(&self) -> bool {
+        match self.half_life {
+            Some(t) => t > PRIMORDIAL_HALF_LIFE,
+            None => true,
+        }
+    }
+}
+
+impl Element {
+    /// The stable isotopes and the notable radioactive ones, by increasing
+    /// mass number. Isotopes such as ⁴⁰K or ²³⁸U that are radioactive but
+    /// found in nature have both a half-life and an abundance.
+    pub fn get_isotopes(&self) -> &'static [Isotope] {
+        let (start, len) = ISOTOPES[*self as usize];
+        &ISOTOPES_DATA[start as usize..start as usize + len as usize]
+    }
+
+    pub fn get_isotope(&self, mass_number: u16) -> Option<&'static Isotope> {
+        self.get_isotopes()
+            .iter()
+            .find(|i| i.mass_number == mass_number)
+    }
+
+    pub fn has_stable_isotopes(&self) -> bool {
+        self.get_isotopes().iter().any(|i| i.is_stable())
+    }
+
+    /// Whether all isotopes of the element are radioactive. Bismuth counts as
+    /// radioactive, even if the half-life of ²⁰⁹Bi is 10⁹ times the age of
+    /// the universe.
+    #[inline(always)]
+    pub fn is_radioactive(&self) -> bool {
+        !self.has_stable_isotopes()
+    }
+
+    /// The isotope with the longest half-life. Among several stable isotopes,
+    /// the most abundant one is returned.
+    pub fn get_longest_lived_isotope(&self) -> &'static Isotope {
+        let half_life = |i: &Isotope| i.half_life.unwrap_or(f64::INFINITY);
+        self.get_isotopes()
+            .iter()
+            .max_by(|a, b| {
+                half_life(a).total_cmp(&half_life(b)).then(
+                    a.abundance
+                        .unwrap_or(0.0)
+                        .total_cmp(&b.abundance.unwrap_or(0.0)),
+                )
+            })
+            .unwrap()
+    }
+
+    pub fn get_natural_occurrence(&self) -> NaturalOccurrence {
+        if self.get_isotopes().iter().any(|i| i.is_primordial()) {
+            NaturalOccurrence::Primordial
+        } else if self.get_atomic_number() <= HEAVIEST_NATURAL_ATOMIC_NUMBER {
+            NaturalOccurrence::FromDecay
+        } else {
+            NaturalOccurrence::Synthetic
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Element;
+
+    #[test]
+    fn common_radionuclides() {
+        let tritium = Element::Hydrogen.get_isotope(3).unwrap();
+        assert!((tritium.get_half_life_in_years().unwrap() - 12.32).abs() < 1e-9);
+        assert_eq!(tritium.get_decays()[0].daughter.element, Element::Helium);
+        let carbon_14 = Element::Carbon.get_isotope(14).unwrap();
+        assert!((carbon_14.get_half_life_in_years().unwrap() - 5700.0).abs() < 1e-6);
+        assert_eq!(
+            carbon_14.get_decays()[0].daughter.element,
+            Element::Nitrogen
+        );
+        assert!(!Element::Carbon.is_radioactive());
+    }
+}
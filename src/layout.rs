@@ -0,0 +1,63 @@
+//! Position of the elements in the standard 18-column periodic table, with
+//! the lanthanides and actinides (from lanthanum and actinium, following
+//! `GroupBlock`) in two separate rows under the main table.
+
+use crate::{Element, SubshellKind};
+
+/// Atomic number of the last element of each period.
+const PERIOD_ENDS: [usize; 7] = [2, 10, 18, 36, 54, 86, 118];
+
+impl Element {
+    /// From 1 to 7.
+    pub fn get_period(&self) -> u8 {
+        let z = self.get_atomic_number();
+        PERIOD_ENDS.iter().position(|&end| z <= end).unwrap() as u8 + 1
+    }
+
+    /// From 1 to 18, `None` for the lanthanides and actinides.
+    pub fn get_group_number(&self) -> Option<u8> {
+        match self.get_table_position() {
+            (row, column) if row < 7 => Some(column + 1),
+            _ => None,
+        }
+    }
+
+    /// The block of the element in this layout, so lanthanum and actinium
+    /// are in the f-block. Helium is in the s-block.
+    pub fn get_block(&self) -> SubshellKind {
+        match self.get_table_position() {
+            (0, _) | (_, 0..=1) => SubshellKind::S,
+            (7..=8, _) => SubshellKind::F,
+            (_, 2..=11) => SubshellKind::D,
+            _ => SubshellKind::P,
+        }
+    }
+
+    /// The row and the column of the element in the table, starting at zero.
+    /// Rows 0 to 6 are the periods of the main table, and rows 7 and 8 hold
+    /// the lanthanides and the actinides, aligned so that lanthanum and
+    /// actinium are in column 2.
+    pub fn get_table_position(&self) -> (u8, u8) {
+        let z = self.get_atomic_number();
+        let period = self.get_period() as usize;
+        let first = if period == 1 {
+            1
+        } else {
+            PERIOD_ENDS[period - 2] + 1
+        };
+        let i = z - first;
+        let (row, column) = match period {
+            1 if i == 0 => (0, 0),
+            1 => (0, 17),
+            2 | 3 if i < 2 => (period - 1, i),
+            2 | 3 => (period - 1, i + 10),
+            4 | 5 => (period - 1, i),
+            _ if i < 2 => (period - 1, i),
+            // The f-block row, from lanthanum or actinium to lutetium or
+            // lawrencium
+            _ if i < 17 => (period + 1, i),
+            _ => (period - 1, i - 14),
+        };
+        (row as u8, column as u8)
+    }
+}
@@ -1,27 +1,127 @@
 //! This is a complete periodic table for rust, including the following fields:
 //! * Atomic number
 //! * Symbol
-//! * Name
+//! * Name (also in German, French, Spanish, Italian, Portuguese, Russian, Chinese and Japanese)
+//! * Historical and provisional names and symbols
 //! * Atomic mass
 //! * CPK Color
+//! * Colour schemes (Jmol, RasMol, PyMOL and by group block)
+//! * Spectral lines and flame test colour
 //! * Electron configuration
-//! * Electronegativity
-//! * Atomic radius
-//! * Ionization energy
+//! * Electronegativity (Pauling, Allen, Allred-Rochow, Mulliken and Sanderson)
+//! * Atomic radius (covalent, metallic, van der Waals and ionic)
+//! * Ionization energies (successive)
 //! * Electron affinity
 //! * Oxidation states
 //! * Standard state
 //! * Melting point
 //! * Boiling point
+//! * Enthalpies of fusion and vaporization, heat capacity, thermal conductivity and entropy
 //! * Density
 //! * Group block
+//! * Period, group, block and position in the table
+//! * Crystal structure
+//! * Electrical resistivity, magnetic ordering and susceptibility, superconductivity
+//! * Isotopes (half-life, natural abundance) and radioactivity
+//! * Decay chains and the Bateman equations
+//! * Nuclear binding energy, binding energy per nucleon and mass defect (measured and semi-empirical)
+//! * Abundance (Earth's crust, seawater, Sun and human body)
+//! * Hazards (GHS pictograms and hazard statements, NFPA 704)
+//! * Identifiers (CAS, PubChem CID, ChEBI, InChI, InChIKey and Wikidata)
 //! * Year discovered
+//! * Discovery (discoverers, countries, laboratories, year isolated and etymology)
+//!
+//! It can also draw the periodic table as SVG or print it to a terminal.
 
 use std::iter::{DoubleEndedIterator, ExactSizeIterator, FusedIterator, Iterator};
 use std::mem;
 
+macro_rules! lookup {
+    ($table:expr, $term:expr) => {{
+        // Binary search the term
+        let mut l = 0;
+        let mut r = $table.len();
+        while l < r {
+            let m = l + (r - l) / 2;
+            if $table[m].0 == $term {
+                return Some(unsafe { Element::from_id($table[m].1) });
+            }
+            if $table[m].0 < $term {
+                l = m + 1;
+            } else {
+                r = m;
+            }
+        }
+        None
+    }};
+}
+
+mod abundance;
+mod binding;
+mod bond;
+mod color;
+mod configuration;
+mod crystal;
+mod decay;
+mod discovery;
+mod electromagnetism;
+mod electronegativity;
+mod extended;
+mod hazard;
+mod identifiers;
+mod isotope;
+mod layout;
+mod locale;
+mod madelung;
+mod names;
+mod radius;
+mod set;
+mod slater;
+mod spectrum;
+mod svg;
+mod term_symbol;
+mod terminal;
+mod thermodynamics;
+
+pub use abundance::{elements_by_abundance, Abundance, Reservoir};
+pub use binding::{
+    binding_energy, binding_energy_per_nucleon, mass_defect, semi_empirical_binding_energy,
+};
+pub use bond::{BondPolarity, BondType};
+pub use color::{Color, ColorScheme};
+pub use configuration::{Subshell, SubshellKind};
+pub use crystal::{Centering, CrystalStructure, CrystalSystem, LatticeConstants};
+pub use decay::{Decay, DecayChain, DecayLink, DecayMode, DecaySeries};
+pub use discovery::{Discovery, DiscoveryYear};
+pub use electromagnetism::MagneticOrdering;
+pub use electronegativity::ElectronegativityScale;
+pub use extended::{systematic_name, systematic_symbol, ExtendedElement, SystematicNumber};
+pub use hazard::{GhsPictogram, HazardSource, Hazards, Nfpa704, NfpaSpecial};
+pub use isotope::{Isotope, NaturalOccurrence};
+pub use locale::Locale;
+pub use madelung::{
+    madelung_configuration, madelung_exceptions, SubshellDifference, MADELUNG_MAX_ATOMIC_NUMBER,
+};
+pub use names::{NameKind, NameRecord};
+pub use radius::{IonicRadius, RadiusKind};
+pub use set::{ElementSet, ElementSetIter};
+pub use spectrum::{identify_spectral_line, SpectralLine};
+pub use svg::SvgTable;
+pub use term_symbol::TermSymbol;
+pub use terminal::TerminalTable;
+
 include!(concat!(env!("OUT_DIR"), "/data.rs"));
 
+/// The generated tables use zero for unknown values.
+#[inline(always)]
+fn known(value: f32) -> Option<f32> {
+    if value > 0.0 {
+        Some(value)
+    } else {
+        None
+    }
+}
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub enum GroupBlock {
     AlkaliMetal,
@@ -36,25 +136,22 @@ pub enum GroupBlock {
     NobleGas,
 }
 
-macro_rules! lookup {
-    ($table:expr, $term:expr) => {{
-        // Binary search the term
-        let mut l = 0;
-        let mut r = 117;
-        while l <= r {
-            let m = l + (r - l) / 2;
-            if $table[m].0 == $term {
-                return Some(unsafe { Element::from_id($table[m].1) });
-            }
-            if $table[m].0 < $term {
-                l = m + 1;
-            }
-            if $table[m].0 > $term {
-                r = m - 1;
-            }
+impl GroupBlock {
+    /// In lowercase, e.g. "alkali metal".
+    pub fn name(&self) -> &'static str {
+        match self {
+            GroupBlock::AlkaliMetal => "alkali metal",
+            GroupBlock::AlkalineEarthMetal => "alkaline earth metal",
+            GroupBlock::Lanthanide => "lanthanide",
+            GroupBlock::Actinide => "actinide",
+            GroupBlock::TransitionMetal => "transition metal",
+            GroupBlock::PostTransitionMetal => "post-transition metal",
+            GroupBlock::Metalloid => "metalloid",
+            GroupBlock::NonMetal => "nonmetal",
+            GroupBlock::Halogen => "halogen",
+            GroupBlock::NobleGas => "noble gas",
         }
-        None
-    }};
+    }
 }
 
 impl Element {
@@ -79,8 +176,13 @@ impl Element {
         lookup!(SYMBOLS_SORTED_ALPHABETICALLY, sym)
     }
 
-    /// Name must be lowercase
+    /// Name must be lowercase. Historical, provisional and variant names
+    /// (e.g. "wolfram", "ununoctium" or "aluminium") are also accepted.
     pub fn from_name(name: &str) -> Option<Element> {
+        Element::from_current_name(name).or_else(|| Element::from_historical_name(name))
+    }
+
+    fn from_current_name(name: &str) -> Option<Element> {
         lookup!(LOWERCASE_NAMES_SORTED_ALPHABETICALLY, name)
     }
 
@@ -115,6 +217,13 @@ impl Element {
         ELECTRONEGATIVITIES[*self as usize]
     }
 
+    /// Like `get_electronegativity` but returns `None` instead of `0.0` for
+    /// elements without a known Pauling electronegativity.
+    #[inline(always)]
+    pub fn get_electronegativity_checked(&self) -> Option<f32> {
+        known(ELECTRONEGATIVITIES[*self as usize])
+    }
+
     #[inline(always)]
     pub fn get_electron_affinity(&self) -> f32 {
         ELECTRON_AFFINITIES[*self as usize]
@@ -141,6 +250,13 @@ impl Element {
         IONIZATION_ENERGIES[*self as usize]
     }
 
+    /// Successive ionization energies in eV, starting with the first one.
+    /// Empty if not even the first ionization energy is known.
+    pub fn get_ionization_energies(&self) -> &'static [f32] {
+        let (start, len) = IONIZATION_ENERGIES_SERIES[*self as usize];
+        &IONIZATION_ENERGIES_DATA[start as usize..start as usize + len as usize]
+    }
+
     #[inline(always)]
     pub fn get_density(&self) -> f32 {
         DENSITIES[*self as usize]
@@ -171,6 +287,9 @@ impl Element {
         NAMES[*self as usize]
     }
 
+    /// As listed by PubChem, 0 for the elements known since antiquity. See
+    /// `get_discovery` for the year from the historical record, which can
+    /// differ.
     #[inline(always)]
     pub fn get_year_discovered(&self) -> u16 {
         YEARS_OF_DISCOVERED[*self as usize]
@@ -0,0 +1,87 @@
+use crate::{Element, LOCALIZED_NAMES, LOWERCASE_LOCALIZED_NAMES_SORTED_ALPHABETICALLY};
+
+/// A language element names are available in.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Locale {
+    English,
+    German,
+    French,
+    Spanish,
+    Italian,
+    Portuguese,
+    Russian,
+    /// Simplified Chinese.
+    Chinese,
+    Japanese,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 9] = [
+        Locale::English,
+        Locale::German,
+        Locale::French,
+        Locale::Spanish,
+        Locale::Italian,
+        Locale::Portuguese,
+        Locale::Russian,
+        Locale::Chinese,
+        Locale::Japanese,
+    ];
+
+    /// The ISO 639-1 code of the language.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Locale::English => "en",
+            Locale::German => "de",
+            Locale::French => "fr",
+            Locale::Spanish => "es",
+            Locale::Italian => "it",
+            Locale::Portuguese => "pt",
+            Locale::Russian => "ru",
+            Locale::Chinese => "zh",
+            Locale::Japanese => "ja",
+        }
+    }
+
+    /// Parses an ISO 639-1 code, ignoring any region subtag (`pt-BR`).
+    pub fn from_code(code: &str) -> Option<Locale> {
+        let language = code.split(['-', '_']).next()?;
+        Locale::ALL
+            .iter()
+            .copied()
+            .find(|l| l.code().eq_ignore_ascii_case(language))
+    }
+}
+
+impl Element {
+    /// The name of the element in the given language.
+    pub fn name_in(&self, locale: Locale) -> &'static str {
+        match locale {
+            Locale::English => self.get_name(),
+            _ => LOCALIZED_NAMES[locale as usize - 1][*self as usize],
+        }
+    }
+
+    /// Looks up an element by its name in the given language, ignoring case.
+    pub fn from_localized_name(name: &str, locale: Locale) -> Option<Element> {
+        match locale {
+            Locale::English => Element::from_name_case_insensitive(name),
+            _ => Element::lookup_localized_name(&name.to_lowercase(), locale),
+        }
+    }
+
+    /// Looks up an element by its name in any of the supported languages,
+    /// ignoring case.
+    pub fn from_any_localized_name(name: &str) -> Option<(Element, Locale)> {
+        Locale::ALL
+            .iter()
+            .find_map(|&l| Element::from_localized_name(name, l).map(|e| (e, l)))
+    }
+
+    fn lookup_localized_name(name: &str, locale: Locale) -> Option<Element> {
+        lookup!(
+            LOWERCASE_LOCALIZED_NAMES_SORTED_ALPHABETICALLY[locale as usize - 1],
+            name
+        )
+    }
+}
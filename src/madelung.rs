@@ -0,0 +1,138 @@
+use crate::{periodic_table, Element, Subshell, SubshellKind};
+
+const SUBSHELL_KINDS: [SubshellKind; 5] = [
+    SubshellKind::S,
+    SubshellKind::P,
+    SubshellKind::D,
+    SubshellKind::F,
+    SubshellKind::G,
+];
+
+/// The highest atomic number `madelung_configuration` can fill without
+/// needing `h` subshells.
+pub const MADELUNG_MAX_ATOMIC_NUMBER: usize = 220;
+
+/// A subshell whose occupation in the ground state differs from the one
+/// predicted by the Madelung rule.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SubshellDifference {
+    pub subshell: Subshell,
+    /// Electrons predicted by the Madelung rule.
+    pub expected: u8,
+    /// Electrons in the ground state configuration.
+    pub actual: u8,
+}
+
+/// Generates the idealized configuration of a neutral atom with `z`
+/// electrons, filling subshells by increasing `n + l` and then increasing
+/// `n`. The subshells are returned in filling order.
+///
+/// Works for hypothetical elements too, `None` is returned if `z` is zero or
+/// greater than `MADELUNG_MAX_ATOMIC_NUMBER`.
+pub fn madelung_configuration(z: usize) -> Option<Vec<(Subshell, u8)>> {
+    if z == 0 || z > MADELUNG_MAX_ATOMIC_NUMBER {
+        return None;
+    }
+    let mut remaining = z;
+    let mut configuration = Vec::new();
+    for sum in 1u8.. {
+        // For a fixed n + l, the lower n (and so the higher l) fills first.
+        for &kind in SUBSHELL_KINDS.iter().rev() {
+            let l = kind.azimuthal_quantum_number();
+            if l >= sum || sum - l <= l {
+                continue;
+            }
+            let electrons = remaining.min(kind.capacity() as usize);
+            configuration.push((Subshell::new(sum - l, kind), electrons as u8));
+            remaining -= electrons;
+            if remaining == 0 {
+                return Some(configuration);
+            }
+        }
+    }
+    unreachable!()
+}
+
+impl Element {
+    /// The subshells where the ground state configuration differs from the
+    /// Madelung rule prediction. Empty for elements that follow the rule.
+    pub fn get_madelung_differences(&self) -> Vec<SubshellDifference> {
+        let actual = self.get_electronic_configuration();
+        let expected = madelung_configuration(self.get_atomic_number()).unwrap();
+        let mut differences = Vec::new();
+        for &(subshell, electrons) in expected.iter() {
+            if actual.get(subshell) != electrons {
+                differences.push(SubshellDifference {
+                    subshell,
+                    expected: electrons,
+                    actual: actual.get(subshell),
+                });
+            }
+        }
+        for (subshell, electrons) in actual.subshells() {
+            if !expected.iter().any(|&(s, _)| s == subshell) {
+                differences.push(SubshellDifference {
+                    subshell,
+                    expected: 0,
+                    actual: electrons,
+                });
+            }
+        }
+        differences.sort_unstable();
+        differences
+    }
+
+    /// Whether the ground state configuration doesn't follow the Madelung rule.
+    pub fn is_madelung_exception(&self) -> bool {
+        !self.get_madelung_differences().is_empty()
+    }
+}
+
+/// All the elements whose ground state configuration doesn't follow the
+/// Madelung rule (Cr, Cu, Pd, ...).
+pub fn madelung_exceptions() -> Vec<Element> {
+    periodic_table()
+        .filter(|e| e.is_madelung_exception())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{madelung_configuration, madelung_exceptions, SubshellDifference};
+    use crate::{Element, Subshell, SubshellKind};
+
+    #[test]
+    fn hypothetical_elements() {
+        let configuration = madelung_configuration(119).unwrap();
+        assert_eq!(
+            configuration.last(),
+            Some(&(Subshell::new(8, SubshellKind::S), 1))
+        );
+        let electrons: usize = configuration.iter().map(|&(_, e)| e as usize).sum();
+        assert_eq!(electrons, 119);
+        assert_eq!(madelung_configuration(0), None);
+        assert_eq!(madelung_configuration(221), None);
+    }
+
+    #[test]
+    fn chromium() {
+        assert_eq!(
+            Element::Chromium.get_madelung_differences(),
+            vec![
+                SubshellDifference {
+                    subshell: Subshell::new(3, SubshellKind::D),
+                    expected: 4,
+                    actual: 5,
+                },
+                SubshellDifference {
+                    subshell: Subshell::new(4, SubshellKind::S),
+                    expected: 2,
+                    actual: 1,
+                },
+            ]
+        );
+        assert!(Element::Iron.get_madelung_differences().is_empty());
+        assert!(madelung_exceptions().contains(&Element::Copper));
+        assert!(!madelung_exceptions().contains(&Element::Zinc));
+    }
+}
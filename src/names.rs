@@ -0,0 +1,86 @@
+use crate::{
+    Element, HISTORICAL_SYMBOLS_SORTED_ALPHABETICALLY,
+    LOWERCASE_HISTORICAL_NAMES_SORTED_ALPHABETICALLY, NAME_HISTORY, NAME_HISTORY_DATA,
+};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum NameKind {
+    /// A name that was officially or commonly used and has been replaced.
+    Former,
+    /// An IUPAC systematic placeholder used until the element was named.
+    Provisional,
+    /// The (usually Latin) name the current symbol derives from.
+    SymbolOrigin,
+    /// An alternative spelling still in use.
+    Variant,
+    /// A symbol that was replaced while the name stayed the same, such as Lw
+    /// for lawrencium. The name of the record is the current name.
+    FormerSymbol,
+}
+
+/// A name an element has had besides its current English one, or a symbol
+/// it has had besides its current one.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct NameRecord {
+    pub name: &'static str,
+    /// The symbol used along with the name, if any.
+    pub symbol: Option<&'static str>,
+    pub kind: NameKind,
+    /// Year the name came into use, if known.
+    pub from: Option<u16>,
+    /// Year the name was replaced, if it was.
+    pub until: Option<u16>,
+}
+
+impl Element {
+    /// Other names the element has had, sorted by the year they came into
+    /// use. Names without a known date come first.
+    pub fn get_name_history(&self) -> &'static [NameRecord] {
+        let (start, len) = NAME_HISTORY[*self as usize];
+        &NAME_HISTORY_DATA[start as usize..start as usize + len as usize]
+    }
+
+    /// Only looks up historical, provisional and variant names. Name must be
+    /// lowercase.
+    pub fn from_historical_name(name: &str) -> Option<Element> {
+        lookup!(LOWERCASE_HISTORICAL_NAMES_SORTED_ALPHABETICALLY, name)
+    }
+
+    /// Looks up symbols that are no longer in use, such as `Cb` or `Uuo`.
+    pub fn from_historical_symbol(sym: &str) -> Option<Element> {
+        lookup!(HISTORICAL_SYMBOLS_SORTED_ALPHABETICALLY, sym)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Element;
+
+    #[test]
+    fn historical_symbols() {
+        assert_eq!(
+            Element::from_historical_symbol("Cb"),
+            Some(Element::Niobium)
+        );
+        assert_eq!(
+            Element::from_historical_symbol("Lw"),
+            Some(Element::Lawrencium)
+        );
+        assert_eq!(
+            Element::from_historical_symbol("Uuo"),
+            Some(Element::Oganesson)
+        );
+        assert_eq!(Element::from_historical_symbol("Fe"), None);
+        assert_eq!(Element::from_historical_symbol("Na"), None);
+    }
+
+    #[test]
+    fn historical_names() {
+        assert_eq!(
+            Element::from_historical_name("columbium"),
+            Some(Element::Niobium)
+        );
+        assert_eq!(Element::from_historical_name("ferrum"), Some(Element::Iron));
+        assert_eq!(Element::from_historical_name("lawrencium"), None);
+    }
+}
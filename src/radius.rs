@@ -0,0 +1,60 @@
+use crate::{
+    known, Element, COVALENT_RADII_DOUBLE, COVALENT_RADII_SINGLE, COVALENT_RADII_TRIPLE,
+    IONIC_RADII, IONIC_RADII_DATA, METALLIC_RADII,
+};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum RadiusKind {
+    /// Single bond covalent radius (Pyykkö and Atsumi).
+    CovalentSingle,
+    /// Double bond covalent radius (Pyykkö and Atsumi).
+    CovalentDouble,
+    /// Triple bond covalent radius (Pyykkö and Atsumi).
+    CovalentTriple,
+    /// Metallic radius for a coordination number of 12.
+    Metallic,
+    /// Van der Waals radius, the same value returned by `get_atomic_radius`.
+    VanDerWaals,
+    /// Shannon effective ionic radius of the ion with the given charge and
+    /// coordination number.
+    Ionic { charge: i8, coordination: u8 },
+}
+
+/// An entry of the Shannon table of effective ionic radii.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct IonicRadius {
+    pub charge: i8,
+    pub coordination: u8,
+    /// In picometers.
+    pub radius: f32,
+}
+
+impl Element {
+    /// Returns the radius of the given kind in picometers, or `None` if it is
+    /// not known for this element.
+    pub fn get_radius(&self, kind: RadiusKind) -> Option<f32> {
+        let id = *self as usize;
+        match kind {
+            RadiusKind::CovalentSingle => known(COVALENT_RADII_SINGLE[id]),
+            RadiusKind::CovalentDouble => known(COVALENT_RADII_DOUBLE[id]),
+            RadiusKind::CovalentTriple => known(COVALENT_RADII_TRIPLE[id]),
+            RadiusKind::Metallic => known(METALLIC_RADII[id]),
+            RadiusKind::VanDerWaals => known(self.get_atomic_radius() as f32),
+            RadiusKind::Ionic {
+                charge,
+                coordination,
+            } => self
+                .get_ionic_radii()
+                .iter()
+                .find(|r| r.charge == charge && r.coordination == coordination)
+                .map(|r| r.radius),
+        }
+    }
+
+    /// All the tabulated Shannon ionic radii of the element, sorted by charge
+    /// and coordination number.
+    pub fn get_ionic_radii(&self) -> &'static [IonicRadius] {
+        let (start, len) = IONIC_RADII[*self as usize];
+        &IONIC_RADII_DATA[start as usize..start as usize + len as usize]
+    }
+}
@@ -0,0 +1,119 @@
+use crate::Element;
+use std::iter::{FromIterator, FusedIterator};
+
+/// A set of elements, stored as a bitset.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct ElementSet(u128);
+
+impl ElementSet {
+    pub const fn new() -> ElementSet {
+        ElementSet(0)
+    }
+
+    /// All the 118 elements.
+    pub const fn all() -> ElementSet {
+        ElementSet((1 << 118) - 1)
+    }
+
+    /// Returns whether the element was not already in the set.
+    pub fn insert(&mut self, element: Element) -> bool {
+        let inserted = !self.contains(element);
+        self.0 |= 1 << element as u8;
+        inserted
+    }
+
+    /// Returns whether the element was in the set.
+    pub fn remove(&mut self, element: Element) -> bool {
+        let removed = self.contains(element);
+        self.0 &= !(1 << element as u8);
+        removed
+    }
+
+    #[inline(always)]
+    pub fn contains(&self, element: Element) -> bool {
+        self.0 & (1 << element as u8) != 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn union(&self, other: &ElementSet) -> ElementSet {
+        ElementSet(self.0 | other.0)
+    }
+
+    pub fn intersection(&self, other: &ElementSet) -> ElementSet {
+        ElementSet(self.0 & other.0)
+    }
+
+    pub fn difference(&self, other: &ElementSet) -> ElementSet {
+        ElementSet(self.0 & !other.0)
+    }
+
+    /// By increasing atomic number.
+    pub fn iter(&self) -> ElementSetIter {
+        ElementSetIter(self.0)
+    }
+}
+
+pub struct ElementSetIter(u128);
+
+impl Iterator for ElementSetIter {
+    type Item = Element;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0 == 0 {
+            return None;
+        }
+        let id = self.0.trailing_zeros() as u8;
+        self.0 &= self.0 - 1;
+        Some(unsafe { Element::from_id(id) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl FusedIterator for ElementSetIter {}
+
+impl ExactSizeIterator for ElementSetIter {}
+
+impl IntoIterator for ElementSet {
+    type Item = Element;
+    type IntoIter = ElementSetIter;
+
+    fn into_iter(self) -> ElementSetIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for &ElementSet {
+    type Item = Element;
+    type IntoIter = ElementSetIter;
+
+    fn into_iter(self) -> ElementSetIter {
+        self.iter()
+    }
+}
+
+impl FromIterator<Element> for ElementSet {
+    fn from_iter<I: IntoIterator<Item = Element>>(iter: I) -> ElementSet {
+        let mut set = ElementSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<Element> for ElementSet {
+    fn extend<I: IntoIterator<Item = Element>>(&mut self, iter: I) {
+        for element in iter {
+            self.insert(element);
+        }
+    }
+}
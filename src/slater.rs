@@ -0,0 +1,100 @@
+use crate::{ElectronicConfiguration, Element, Subshell, SubshellKind};
+
+/// Slater groups are `[1s] [2s, 2p] [3s, 3p] [3d] [4s, 4p] [4d] [4f] ...`,
+/// this returns a key that orders them.
+fn group(subshell: Subshell) -> (u8, u8) {
+    match subshell.kind {
+        SubshellKind::S | SubshellKind::P => (subshell.n, 0),
+        SubshellKind::D => (subshell.n, 1),
+        SubshellKind::F => (subshell.n, 2),
+        SubshellKind::G => (subshell.n, 3),
+    }
+}
+
+impl ElectronicConfiguration {
+    /// The shielding constant felt by an electron of `subshell` according to
+    /// Slater's rules, or `None` if the subshell is empty.
+    pub fn slater_shielding(&self, subshell: Subshell) -> Option<f32> {
+        if self.get(subshell) == 0 {
+            return None;
+        }
+        let target = group(subshell);
+        let mut shielding = 0.0;
+        for (other, electrons) in self.subshells() {
+            let other_group = group(other);
+            if other_group > target {
+                // Electrons to the right of the target group don't shield.
+                continue;
+            }
+            let electrons = electrons as f32;
+            if other_group == target {
+                let same = if other == subshell {
+                    electrons - 1.0
+                } else {
+                    electrons
+                };
+                shielding += same * if subshell.n == 1 { 0.30 } else { 0.35 };
+            } else if let SubshellKind::D | SubshellKind::F | SubshellKind::G = subshell.kind {
+                shielding += electrons;
+            } else if other.n + 1 == subshell.n {
+                shielding += 0.85 * electrons;
+            } else if other.n < subshell.n {
+                shielding += electrons;
+            }
+        }
+        Some(shielding)
+    }
+}
+
+impl Element {
+    /// The effective nuclear charge `Z - S` felt by an electron of `subshell`,
+    /// with the shielding `S` given by Slater's rules. `None` if the subshell
+    /// is empty in the ground state configuration.
+    pub fn get_effective_nuclear_charge(&self, subshell: Subshell) -> Option<f32> {
+        let shielding = self
+            .get_electronic_configuration()
+            .slater_shielding(subshell)?;
+        Some(self.get_atomic_number() as f32 - shielding)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Element, Subshell, SubshellKind};
+
+    fn effective_charge(element: Element, n: u8, kind: SubshellKind) -> f32 {
+        element
+            .get_effective_nuclear_charge(Subshell::new(n, kind))
+            .unwrap()
+    }
+
+    #[test]
+    fn textbook_values() {
+        for &(element, n, kind, expected) in &[
+            (Element::Zinc, 4, SubshellKind::S, 4.35),
+            (Element::Zinc, 3, SubshellKind::D, 8.85),
+            (Element::Nitrogen, 2, SubshellKind::P, 3.90),
+            (Element::Hydrogen, 1, SubshellKind::S, 1.0),
+            (Element::Helium, 1, SubshellKind::S, 1.70),
+        ] {
+            let charge = effective_charge(element, n, kind);
+            assert!(
+                (charge - expected).abs() < 1e-4,
+                "{:?} {}{:?}: {}",
+                element,
+                n,
+                kind,
+                charge
+            );
+        }
+    }
+
+    #[test]
+    fn empty_subshell() {
+        let subshell = Subshell::new(3, SubshellKind::D);
+        assert_eq!(
+            Element::Calcium.get_effective_nuclear_charge(subshell),
+            None
+        );
+    }
+}
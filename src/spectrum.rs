@@ -0,0 +1,109 @@
+//! The strongest atomic emission lines of the most common elements, with
+//! wavelengths in air in nanometers (in vacuum below 200 nm).
+
+use crate::{Color, Element, SPECTRAL_LINES, SPECTRAL_LINES_DATA};
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SpectralLine {
+    pub element: Element,
+    /// In nanometers.
+    pub wavelength: f32,
+    /// Relative to the strongest line of the element, which has 1000.
+    pub intensity: u16,
+    /// 1 for the neutral atom (I), 2 for the singly charged ion (II).
+    pub ionization_stage: u8,
+    /// Whether the line gives its colour to the flame test.
+    pub in_flame: bool,
+}
+
+/// Piecewise Gaussian used to fit the CIE color matching functions.
+fn lobe(x: f32, mu: f32, sigma_low: f32, sigma_high: f32) -> f32 {
+    let t = (x - mu) / if x < mu { sigma_low } else { sigma_high };
+    (-0.5 * t * t).exp()
+}
+
+/// The CIE 1931 XYZ tristimulus values of monochromatic light, using the
+/// multi-lobe fit of Wyman, Sloan and Shirley (2013).
+fn wavelength_to_xyz(wavelength: f32) -> [f32; 3] {
+    let w = wavelength;
+    [
+        1.056 * lobe(w, 599.8, 37.9, 31.0) + 0.362 * lobe(w, 442.0, 16.0, 26.7)
+            - 0.065 * lobe(w, 501.1, 20.4, 26.2),
+        0.821 * lobe(w, 568.8, 46.9, 40.5) + 0.286 * lobe(w, 530.9, 16.3, 31.1),
+        1.217 * lobe(w, 437.0, 11.8, 36.0) + 0.681 * lobe(w, 459.0, 26.0, 13.8),
+    ]
+}
+
+/// Converts XYZ to sRGB, clipping the colours outside of its gamut and
+/// scaling the result so that its brightest channel is 255.
+fn xyz_to_srgb(xyz: [f32; 3]) -> Option<Color> {
+    let [x, y, z] = xyz;
+    let rgb = [
+        (3.2406 * x - 1.5372 * y - 0.4986 * z).max(0.0),
+        (-0.9689 * x + 1.8758 * y + 0.0415 * z).max(0.0),
+        (0.0557 * x - 0.204 * y + 1.057 * z).max(0.0),
+    ];
+    let max = rgb[0].max(rgb[1]).max(rgb[2]);
+    if max <= 0.0 {
+        return None;
+    }
+    let channel = |c: f32| ((c / max).powf(1.0 / 2.2) * 255.0).round() as u8;
+    Some(Color::new(
+        channel(rgb[0]),
+        channel(rgb[1]),
+        channel(rgb[2]),
+    ))
+}
+
+impl SpectralLine {
+    #[inline(always)]
+    pub fn is_visible(&self) -> bool {
+        (380.0..=780.0).contains(&self.wavelength)
+    }
+
+    /// The colour of the line, `None` if it is outside of the visible
+    /// spectrum.
+    pub fn get_color(&self) -> Option<Color> {
+        if !self.is_visible() {
+            return None;
+        }
+        xyz_to_srgb(wavelength_to_xyz(self.wavelength))
+    }
+}
+
+impl Element {
+    /// By increasing wavelength. Empty for the elements without tabulated
+    /// lines.
+    pub fn get_spectral_lines(&self) -> &'static [SpectralLine] {
+        let (start, len) = SPECTRAL_LINES[*self as usize];
+        &SPECTRAL_LINES_DATA[start as usize..start as usize + len as usize]
+    }
+
+    /// The colour of the flame test, mixing the lines that appear in a flame
+    /// by intensity and weighting them by the sensitivity of the eye. `None`
+    /// for the elements that do not colour a flame.
+    pub fn get_flame_color(&self) -> Option<Color> {
+        let mut xyz = [0.0; 3];
+        for line in self.get_spectral_lines().iter().filter(|l| l.in_flame) {
+            let line_xyz = wavelength_to_xyz(line.wavelength);
+            for (c, line_c) in xyz.iter_mut().zip(line_xyz.iter()) {
+                *c += line_c * line.intensity as f32;
+            }
+        }
+        xyz_to_srgb(xyz)
+    }
+}
+
+/// The lines within `tolerance` nanometers of `wavelength`, closest first.
+pub fn identify_spectral_line(wavelength: f32, tolerance: f32) -> Vec<&'static SpectralLine> {
+    let mut lines: Vec<_> = SPECTRAL_LINES_DATA
+        .iter()
+        .filter(|l| (l.wavelength - wavelength).abs() <= tolerance)
+        .collect();
+    lines.sort_by(|a, b| {
+        (a.wavelength - wavelength)
+            .abs()
+            .total_cmp(&(b.wavelength - wavelength).abs())
+    });
+    lines
+}
@@ -0,0 +1,307 @@
+//! Drawing of the periodic table as SVG.
+
+use crate::color::{text_color, MISSING};
+use crate::{periodic_table, Color, ColorScheme, Element, GroupBlock};
+use std::fmt::Write;
+
+const BORDER: Color = Color::new(0x55, 0x55, 0x55);
+const GROUP_BLOCKS: [GroupBlock; 10] = [
+    GroupBlock::AlkaliMetal,
+    GroupBlock::AlkalineEarthMetal,
+    GroupBlock::TransitionMetal,
+    GroupBlock::PostTransitionMetal,
+    GroupBlock::Metalloid,
+    GroupBlock::NonMetal,
+    GroupBlock::Halogen,
+    GroupBlock::NobleGas,
+    GroupBlock::Lanthanide,
+    GroupBlock::Actinide,
+];
+
+/// Uppercases the first letter of an ASCII name.
+fn capitalize(name: &str) -> String {
+    name[..1].to_ascii_uppercase() + &name[1..]
+}
+
+/// Linear interpolation between two colours, `t` going from 0 to 1.
+fn mix(low: Color, high: Color, t: f64) -> Color {
+    let channel = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+    Color::new(
+        channel(low.r, high.r),
+        channel(low.g, high.g),
+        channel(low.b, high.b),
+    )
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// With four significant digits, without trailing zeros.
+fn format_number(value: f64) -> String {
+    if value == 0.0 {
+        return "0".to_owned();
+    }
+    let decimals = (3 - value.abs().log10().floor() as i32).max(0) as usize;
+    let text = format!("{:.*}", decimals, value);
+    if text.contains('.') {
+        text.trim_end_matches('0').trim_end_matches('.').to_owned()
+    } else {
+        text
+    }
+}
+
+type Property = Box<dyn Fn(Element) -> Option<f64>>;
+type Label = Box<dyn Fn(Element) -> Option<String>>;
+
+enum Coloring {
+    Scheme(ColorScheme),
+    Ramp(Property),
+}
+
+/// Renders the 18-column periodic table, with the lanthanides and actinides
+/// below it, as an SVG image. Each cell shows the atomic number, the symbol
+/// and an optional label.
+pub struct SvgTable {
+    coloring: Coloring,
+    low: Color,
+    high: Color,
+    label: Option<Label>,
+    cell_size: f32,
+}
+
+impl Default for SvgTable {
+    fn default() -> SvgTable {
+        SvgTable::new()
+    }
+}
+
+impl SvgTable {
+    /// Coloured by group block, without labels, with cells of 50 pixels.
+    pub fn new() -> SvgTable {
+        SvgTable {
+            coloring: Coloring::Scheme(ColorScheme::Category),
+            low: Color::new(0xFF, 0xFF, 0xCC),
+            high: Color::new(0xBD, 0x00, 0x26),
+            label: None,
+            cell_size: 50.0,
+        }
+    }
+
+    /// Colours the cells with one of the colour schemes. Only the `Category`
+    /// scheme gets a legend.
+    pub fn color_by(mut self, scheme: ColorScheme) -> SvgTable {
+        self.coloring = Coloring::Scheme(scheme);
+        self
+    }
+
+    /// Colours the cells on a linear ramp between the smallest and the
+    /// largest of the values, with a legend. Cells without a value are grey.
+    pub fn color_by_value<F>(mut self, value: F) -> SvgTable
+    where
+        F: Fn(Element) -> Option<f64> + 'static,
+    {
+        self.coloring = Coloring::Ramp(Box::new(value));
+        self
+    }
+
+    /// The colours of the smallest and the largest values for
+    /// `color_by_value`, pale yellow and dark red by default.
+    pub fn ramp_colors(mut self, low: Color, high: Color) -> SvgTable {
+        self.low = low;
+        self.high = high;
+        self
+    }
+
+    /// Text written under the symbol, e.g. a formatted property.
+    pub fn label<F>(mut self, label: F) -> SvgTable
+    where
+        F: Fn(Element) -> Option<String> + 'static,
+    {
+        self.label = Some(Box::new(label));
+        self
+    }
+
+    /// Width and height of the cells in pixels.
+    pub fn cell_size(mut self, size: f32) -> SvgTable {
+        self.cell_size = size;
+        self
+    }
+
+    /// Coordinates of the top left corner of a cell.
+    fn cell_origin(&self, element: Element) -> (f32, f32) {
+        let (row, column) = element.get_table_position();
+        let size = self.cell_size;
+        // Half a cell between the main table and the f-block rows
+        let gap = if row >= 7 { size / 2.0 } else { 0.0 };
+        (
+            size / 2.0 + column as f32 * size,
+            size / 2.0 + row as f32 * size + gap,
+        )
+    }
+
+    pub fn render(&self) -> String {
+        let size = self.cell_size;
+        let width = 19.0 * size;
+        let height = 10.5 * size;
+        let mut svg = String::new();
+        writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}" font-family="sans-serif">"#,
+            w = width,
+            h = height
+        )
+        .unwrap();
+        let values: Vec<Option<f64>> = match &self.coloring {
+            Coloring::Scheme(_) => vec![None; 118],
+            Coloring::Ramp(value) => periodic_table()
+                .map(|e| value(e).filter(|v| v.is_finite()))
+                .collect(),
+        };
+        let range = values.iter().flatten().fold(None, |range, &v| match range {
+            None => Some((v, v)),
+            Some((min, max)) => Some((v.min(min), v.max(max))),
+        });
+        for element in periodic_table() {
+            let fill = match &self.coloring {
+                Coloring::Scheme(scheme) => element.get_color(*scheme),
+                Coloring::Ramp(_) => values[element as usize].zip(range).map(|(v, (min, max))| {
+                    let t = if max > min {
+                        (v - min) / (max - min)
+                    } else {
+                        0.0
+                    };
+                    mix(self.low, self.high, t)
+                }),
+            }
+            .unwrap_or(MISSING);
+            self.render_cell(&mut svg, element, fill);
+        }
+        match &self.coloring {
+            Coloring::Scheme(ColorScheme::Category) => self.render_category_legend(&mut svg),
+            Coloring::Ramp(_) => {
+                if let Some((min, max)) = range {
+                    self.render_ramp_legend(&mut svg, min, max);
+                }
+            }
+            Coloring::Scheme(_) => {}
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    fn render_cell(&self, svg: &mut String, element: Element, fill: Color) {
+        let size = self.cell_size;
+        let (x, y) = self.cell_origin(element);
+        let text = text_color(fill);
+        writeln!(
+            svg,
+            r#"<g><title>{}</title><rect x="{}" y="{}" width="{}" height="{}" fill="{}" stroke="{}" stroke-width="1"/>"#,
+            element.get_name(),
+            x,
+            y,
+            size,
+            size,
+            fill,
+            BORDER
+        )
+        .unwrap();
+        writeln!(
+            svg,
+            r#"<text x="{}" y="{}" font-size="{}" fill="{}">{}</text>"#,
+            x + size * 0.08,
+            y + size * 0.22,
+            size * 0.18,
+            text,
+            element.get_atomic_number()
+        )
+        .unwrap();
+        writeln!(
+            svg,
+            r#"<text x="{}" y="{}" font-size="{}" font-weight="bold" text-anchor="middle" fill="{}">{}</text>"#,
+            x + size / 2.0,
+            y + size * 0.62,
+            size * 0.36,
+            text,
+            element.get_symbol()
+        )
+        .unwrap();
+        if let Some(label) = self.label.as_ref().and_then(|label| label(element)) {
+            writeln!(
+                svg,
+                r#"<text x="{}" y="{}" font-size="{}" text-anchor="middle" fill="{}">{}</text>"#,
+                x + size / 2.0,
+                y + size * 0.88,
+                size * 0.15,
+                text,
+                escape(&label)
+            )
+            .unwrap();
+        }
+        svg.push_str("</g>\n");
+    }
+
+    /// Drawn in the empty space above the transition metals.
+    fn render_category_legend(&self, svg: &mut String) {
+        let size = self.cell_size;
+        for (i, &group) in GROUP_BLOCKS.iter().enumerate() {
+            let x = 3.0 * size + (i / 5) as f32 * 4.5 * size;
+            let y = 0.7 * size + (i % 5) as f32 * 0.45 * size;
+            writeln!(
+                svg,
+                r#"<rect x="{}" y="{}" width="{s}" height="{s}" fill="{}" stroke="{}" stroke-width="1"/>"#,
+                x,
+                y,
+                group.get_color(),
+                BORDER,
+                s = size * 0.3
+            )
+            .unwrap();
+            writeln!(
+                svg,
+                r#"<text x="{}" y="{}" font-size="{}">{}</text>"#,
+                x + size * 0.45,
+                y + size * 0.25,
+                size * 0.25,
+                capitalize(group.name())
+            )
+            .unwrap();
+        }
+    }
+
+    fn render_ramp_legend(&self, svg: &mut String, min: f64, max: f64) {
+        let size = self.cell_size;
+        let (x, y, width) = (3.0 * size, 1.0 * size, 9.0 * size);
+        writeln!(
+            svg,
+            r#"<defs><linearGradient id="ramp"><stop offset="0" stop-color="{}"/><stop offset="1" stop-color="{}"/></linearGradient></defs>"#,
+            self.low, self.high
+        )
+        .unwrap();
+        writeln!(
+            svg,
+            r#"<rect x="{}" y="{}" width="{}" height="{}" fill="url(#ramp)" stroke="{}" stroke-width="1"/>"#,
+            x,
+            y,
+            width,
+            size * 0.4,
+            BORDER
+        )
+        .unwrap();
+        for (value, anchor, x) in [(min, "start", x), (max, "end", x + width)].iter() {
+            writeln!(
+                svg,
+                r#"<text x="{}" y="{}" font-size="{}" text-anchor="{}">{}</text>"#,
+                x,
+                y + size * 0.75,
+                size * 0.25,
+                anchor,
+                format_number(*value)
+            )
+            .unwrap();
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use std::fmt;
+
+use crate::{ElectronicConfiguration, Element};
+
+const ORBITAL_LETTERS: &[u8] = b"SPDFGHIKLMNOQRTUV";
+const SUPERSCRIPT_DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+const SUBSCRIPT_DIGITS: [char; 10] = ['₀', '₁', '₂', '₃', '₄', '₅', '₆', '₇', '₈', '₉'];
+
+/// A Russell-Saunders term symbol `^{2S+1}L_J`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TermSymbol {
+    /// Total spin quantum number.
+    pub s: f32,
+    /// Total orbital angular momentum quantum number.
+    pub l: u8,
+    /// Total angular momentum quantum number.
+    pub j: f32,
+}
+
+impl TermSymbol {
+    /// The spin multiplicity `2S + 1`.
+    #[inline(always)]
+    pub fn multiplicity(&self) -> u8 {
+        (2.0 * self.s) as u8 + 1
+    }
+}
+
+fn write_digits(f: &mut fmt::Formatter, n: u32, digits: &[char; 10]) -> fmt::Result {
+    for c in n.to_string().bytes() {
+        write!(f, "{}", digits[(c - b'0') as usize])?;
+    }
+    Ok(())
+}
+
+impl fmt::Display for TermSymbol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_digits(f, self.multiplicity() as u32, &SUPERSCRIPT_DIGITS)?;
+        match ORBITAL_LETTERS.get(self.l as usize) {
+            Some(&letter) => write!(f, "{}", letter as char)?,
+            None => write!(f, "[L={}]", self.l)?,
+        }
+        let two_j = (2.0 * self.j) as u32;
+        if two_j % 2 == 1 {
+            write_digits(f, two_j, &SUBSCRIPT_DIGITS)?;
+            write!(f, "/")?;
+            write_digits(f, 2, &SUBSCRIPT_DIGITS)
+        } else {
+            write_digits(f, two_j / 2, &SUBSCRIPT_DIGITS)
+        }
+    }
+}
+
+impl ElectronicConfiguration {
+    /// The ground state term symbol predicted by Hund's rules.
+    ///
+    /// When there are several open subshells their spins and orbital angular
+    /// momenta are maximized together, so a few elements with strongly mixed
+    /// configurations (e.g. cerium) differ from the spectroscopic ground state.
+    pub fn term_symbol(&self) -> TermSymbol {
+        // Doubled spin to keep it integral.
+        let mut two_s = 0i32;
+        let mut l = 0i32;
+        let mut open_electrons = 0u32;
+        let mut open_capacity = 0u32;
+        for (subshell, electrons) in self.subshells() {
+            let capacity = subshell.kind.capacity();
+            if electrons >= capacity {
+                continue;
+            }
+            let orbitals = capacity as i32 / 2;
+            let azimuthal = subshell.kind.azimuthal_quantum_number() as i32;
+            let up = (electrons as i32).min(orbitals);
+            let down = electrons as i32 - up;
+            two_s += up - down;
+            // Electrons fill m_l = l, l - 1, ... first with spin up, then down.
+            let ml_sum = |count: i32| (0..count).map(|i| azimuthal - i).sum::<i32>();
+            l += (ml_sum(up) + ml_sum(down)).abs();
+            open_electrons += electrons as u32;
+            open_capacity += capacity as u32;
+        }
+        let two_l = 2 * l;
+        let two_j = if 2 * open_electrons < open_capacity {
+            (two_l - two_s).abs()
+        } else {
+            two_l + two_s
+        };
+        TermSymbol {
+            s: two_s as f32 / 2.0,
+            l: l as u8,
+            j: two_j as f32 / 2.0,
+        }
+    }
+}
+
+impl Element {
+    /// The ground state term symbol, see `ElectronicConfiguration::term_symbol`.
+    pub fn get_term_symbol(&self) -> TermSymbol {
+        self.get_electronic_configuration().term_symbol()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TermSymbol;
+    use crate::Element;
+
+    #[test]
+    fn ground_states() {
+        for &(element, term) in &[
+            (Element::Hydrogen, "²S₁/₂"),
+            (Element::Helium, "¹S₀"),
+            (Element::Carbon, "³P₀"),
+            (Element::Oxygen, "³P₂"),
+            (Element::Iron, "⁵D₄"),
+            (Element::Niobium, "⁶D₁/₂"),
+            (Element::Tungsten, "⁵D₀"),
+        ] {
+            assert_eq!(element.get_term_symbol().to_string(), term, "{:?}", element);
+        }
+    }
+
+    #[test]
+    fn display() {
+        let term = TermSymbol {
+            s: 1.5,
+            l: 3,
+            j: 1.5,
+        };
+        assert_eq!(term.multiplicity(), 4);
+        assert_eq!(term.to_string(), "⁴F₃/₂");
+        let term = TermSymbol {
+            s: 3.5,
+            l: 0,
+            j: 3.5,
+        };
+        assert_eq!(term.to_string(), "⁸S₇/₂");
+        let term = TermSymbol {
+            s: 1.0,
+            l: 20,
+            j: 21.0,
+        };
+        assert_eq!(term.to_string(), "³[L=20]₂₁");
+    }
+}
@@ -0,0 +1,133 @@
+//! Printing of the periodic table to a terminal, with ANSI 24-bit colours.
+
+use crate::color::{text_color, MISSING};
+use crate::{periodic_table, Color, ColorScheme, Element, ElementSet};
+
+const RESET: &str = "\x1b[0m";
+/// Colour of the symbols of the elements outside of the highlighted set.
+const DIMMED: Color = Color::new(0x80, 0x80, 0x80);
+
+/// Renders the 18-column periodic table, with the lanthanides and actinides
+/// below it, as lines of text with ANSI escape codes for 24-bit colours.
+///
+/// Compact mode takes 53 columns and one line per row with just the
+/// symbols, and wide mode takes 89 columns and two lines per row to add the
+/// atomic numbers.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct TerminalTable {
+    scheme: ColorScheme,
+    highlight: Option<ElementSet>,
+    compact: bool,
+}
+
+impl Default for TerminalTable {
+    fn default() -> TerminalTable {
+        TerminalTable::new()
+    }
+}
+
+impl TerminalTable {
+    /// Coloured by group block, in wide mode, without highlighting.
+    pub fn new() -> TerminalTable {
+        TerminalTable {
+            scheme: ColorScheme::Category,
+            highlight: None,
+            compact: false,
+        }
+    }
+
+    pub fn color_by(mut self, scheme: ColorScheme) -> TerminalTable {
+        self.scheme = scheme;
+        self
+    }
+
+    /// Only colours the elements of `elements`, in bold, and greys out the
+    /// others.
+    pub fn highlight(mut self, elements: ElementSet) -> TerminalTable {
+        self.highlight = Some(elements);
+        self
+    }
+
+    pub fn compact(mut self, compact: bool) -> TerminalTable {
+        self.compact = compact;
+        self
+    }
+
+    /// Writes `text` in the cell of `element`, padded to the cell width.
+    fn cell(&self, element: Element, text: &str) -> String {
+        let width = if self.compact { 2 } else { 4 };
+        if matches!(self.highlight, Some(set) if !set.contains(element)) {
+            // Without a background the padding goes outside of the escape
+            // codes, so that it can be trimmed at the end of a line
+            let padded = format!("{:^w$}", text, w = width);
+            let start = padded.len() - padded.trim_start().len();
+            let end = padded.trim_end().len();
+            return format!(
+                "{}\x1b[38;2;{};{};{}m{}{}{}",
+                &padded[..start],
+                DIMMED.r,
+                DIMMED.g,
+                DIMMED.b,
+                &padded[start..end],
+                RESET,
+                &padded[end..]
+            );
+        }
+        let background = element.get_color(self.scheme).unwrap_or(MISSING);
+        let foreground = text_color(background);
+        let bold = if self.highlight.is_some() {
+            "\x1b[1m"
+        } else {
+            ""
+        };
+        format!(
+            "{}\x1b[48;2;{};{};{}m\x1b[38;2;{};{};{}m{:^w$}{}",
+            bold,
+            background.r,
+            background.g,
+            background.b,
+            foreground.r,
+            foreground.g,
+            foreground.b,
+            text,
+            RESET,
+            w = width
+        )
+    }
+
+    pub fn render(&self) -> String {
+        let width = if self.compact { 2 } else { 4 };
+        let mut grid: [[Option<Element>; 18]; 9] = [[None; 18]; 9];
+        for element in periodic_table() {
+            let (row, column) = element.get_table_position();
+            grid[row as usize][column as usize] = Some(element);
+        }
+        let mut lines = Vec::new();
+        for (i, row) in grid.iter().enumerate() {
+            // Blank line between the main table and the f-block rows
+            if i == 7 {
+                lines.push(String::new());
+            }
+            let render_line = |text: &dyn Fn(Element) -> String| {
+                let cells: Vec<String> = row
+                    .iter()
+                    .map(|cell| match cell {
+                        Some(element) => self.cell(*element, &text(*element)),
+                        None => " ".repeat(width),
+                    })
+                    .collect();
+                // Removes the empty cells and the padding of dimmed cells at
+                // the end of the line, the padding of coloured cells shows
+                // their background
+                cells.join(" ").trim_end().to_owned()
+            };
+            if !self.compact {
+                lines.push(render_line(&|e| e.get_atomic_number().to_string()));
+            }
+            lines.push(render_line(&|e| e.get_symbol().to_owned()));
+        }
+        let mut table = lines.join("\n");
+        table.push('\n');
+        table
+    }
+}
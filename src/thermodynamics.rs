@@ -0,0 +1,46 @@
+//! For elements made of diatomic molecules (H₂, N₂, O₂, F₂, Cl₂, Br₂ and I₂)
+//! the molar quantities are per mole of molecules.
+
+use crate::{
+    known, Element, ENTHALPIES_OF_FUSION, ENTHALPIES_OF_VAPORIZATION, MOLAR_HEAT_CAPACITIES,
+    SPECIFIC_HEATS, STANDARD_ENTROPIES, THERMAL_CONDUCTIVITIES,
+};
+
+impl Element {
+    /// In kJ/mol.
+    #[inline(always)]
+    pub fn get_enthalpy_of_fusion(&self) -> Option<f32> {
+        known(ENTHALPIES_OF_FUSION[*self as usize])
+    }
+
+    /// In kJ/mol. For elements that sublime (carbon, arsenic) this is the
+    /// enthalpy of sublimation.
+    #[inline(always)]
+    pub fn get_enthalpy_of_vaporization(&self) -> Option<f32> {
+        known(ENTHALPIES_OF_VAPORIZATION[*self as usize])
+    }
+
+    /// At 298.15 K in J/(mol·K).
+    #[inline(always)]
+    pub fn get_molar_heat_capacity(&self) -> Option<f32> {
+        known(MOLAR_HEAT_CAPACITIES[*self as usize])
+    }
+
+    /// At 298.15 K in J/(g·K).
+    #[inline(always)]
+    pub fn get_specific_heat(&self) -> Option<f32> {
+        known(SPECIFIC_HEATS[*self as usize])
+    }
+
+    /// At 300 K in W/(m·K).
+    #[inline(always)]
+    pub fn get_thermal_conductivity(&self) -> Option<f32> {
+        known(THERMAL_CONDUCTIVITIES[*self as usize])
+    }
+
+    /// Standard molar entropy S° at 298.15 K in J/(mol·K).
+    #[inline(always)]
+    pub fn get_standard_entropy(&self) -> Option<f32> {
+        known(STANDARD_ENTROPIES[*self as usize])
+    }
+}